@@ -23,6 +23,9 @@ pub enum CoreError {
     #[error("Terminal error: {0}")]
     Terminal(String),
 
+    #[error("PTY unavailable: {detail} (is /dev/pts mounted in this container?)")]
+    PtyUnavailable { detail: String },
+
     #[error("Connection error: {0}")]
     Connection(String),
 
@@ -70,6 +73,9 @@ pub enum CoreError {
     #[error("QR code generation error: {0}")]
     QrGenerationError(String),
 
+    #[error("Invalid signature")]
+    InvalidSignature,
+
     #[error("Fingerprint mismatch for host {host}: expected {expected}, got {got}")]
     FingerprintMismatch {
         host: String,
@@ -143,4 +149,12 @@ mod tests {
         let err = CoreError::InvalidHandshake;
         assert_eq!(err.to_string(), "Invalid handshake message");
     }
+
+    #[test]
+    fn test_pty_unavailable_error() {
+        let err = CoreError::PtyUnavailable { detail: "No such file or directory".to_string() };
+        let msg = err.to_string();
+        assert!(msg.contains("No such file or directory"));
+        assert!(msg.contains("/dev/pts"));
+    }
 }
@@ -0,0 +1,62 @@
+//! Server event sink for telemetry integration
+//!
+//! The host's QUIC server reports connection, auth, session and traffic events
+//! through an `EventSink`. Operators can implement the trait to forward events
+//! to their own telemetry backend (StatsD, OpenTelemetry, ...).
+
+use std::net::SocketAddr;
+
+/// Event emitted by the QUIC server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServerEvent {
+    /// Client connection established
+    Connected { peer_addr: SocketAddr },
+    /// Client connection ended
+    Disconnected { peer_addr: SocketAddr },
+    /// Hello with a valid auth token
+    AuthSucceeded { peer_addr: SocketAddr },
+    /// Hello with a missing or invalid auth token
+    AuthFailed { peer_addr: SocketAddr },
+    /// PTY session created (legacy sessions use their numeric ID as string)
+    SessionCreated { peer_addr: SocketAddr, session_id: String },
+    /// PTY session closed by client or on disconnect
+    SessionClosed { peer_addr: SocketAddr, session_id: String },
+    /// Bytes read from the client stream
+    BytesReceived { peer_addr: SocketAddr, bytes: u64 },
+    /// Terminal output bytes forwarded to the client
+    BytesSent { peer_addr: SocketAddr, bytes: u64 },
+}
+
+/// Sink for server events
+///
+/// Called inline on the connection task, so implementations should not block.
+pub trait EventSink: Send + Sync {
+    fn record(&self, event: ServerEvent);
+}
+
+/// Sink that discards all events (default)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopEventSink;
+
+impl EventSink for NoopEventSink {
+    fn record(&self, _event: ServerEvent) {}
+}
+
+/// Sink that logs events via tracing
+///
+/// Byte counters are logged at trace level, everything else at debug.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TracingEventSink;
+
+impl EventSink for TracingEventSink {
+    fn record(&self, event: ServerEvent) {
+        match event {
+            ServerEvent::BytesReceived { .. } | ServerEvent::BytesSent { .. } => {
+                tracing::trace!(target: "comacode::events", "{:?}", event);
+            }
+            _ => {
+                tracing::debug!(target: "comacode::events", "{:?}", event);
+            }
+        }
+    }
+}
@@ -8,12 +8,15 @@
 //! - Authentication types (Phase E03)
 
 // Version constants
-pub const PROTOCOL_VERSION: u32 = 1;
+// v2: FlowControl/completion/chunked file/write/shell messages,
+//     locale and shell in CreateSession, charset in SessionCreated
+pub const PROTOCOL_VERSION: u32 = 2;
 pub const APP_VERSION_STRING: &str = "0.1.0-mvp";
 pub const SNAPSHOT_BUFFER_LINES: usize = 1000;
 
 pub mod auth;
 pub mod error;
+pub mod events;
 pub mod protocol;
 pub mod streaming;
 pub mod terminal;
@@ -23,10 +26,11 @@ pub mod types;
 // Re-export common types
 pub use auth::AuthToken;
 pub use error::{CoreError, Result};
+pub use events::{EventSink, ServerEvent};
 pub use protocol::MessageCodec;
 pub use streaming::OutputStream;
 pub use terminal::{Terminal, TerminalConfig, MockTerminal};
-pub use types::{NetworkMessage, TerminalCommand, TerminalEvent, QrPayload, FileEventType};
+pub use types::{NetworkMessage, TerminalCommand, TerminalEvent, QrPayload, FileEventType, WriteError};
 
 #[cfg(test)]
 mod tests {
@@ -34,7 +38,7 @@ mod tests {
 
     #[test]
     fn test_version_constants_defined() {
-        assert_eq!(PROTOCOL_VERSION, 1);
+        assert_eq!(PROTOCOL_VERSION, 2);
         assert!(APP_VERSION_STRING.starts_with("0.1.0"));
     }
 }
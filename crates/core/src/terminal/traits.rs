@@ -105,6 +105,46 @@ impl TerminalConfig {
         self.env.push((key, value));
         self
     }
+
+    /// Set session locale (LANG and LC_ALL)
+    ///
+    /// Locale without charset gets `.UTF-8` appended ("vi_VN" → "vi_VN.UTF-8").
+    /// Invalid locale names are ignored and the current locale is kept.
+    pub fn with_locale(mut self, locale: &str) -> Self {
+        let valid = !locale.is_empty()
+            && locale.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-' | '@'));
+        if !valid {
+            tracing::warn!("Ignoring invalid locale: {:?}", locale);
+            return self;
+        }
+
+        let locale = if locale.contains('.') || locale == "C" || locale == "POSIX" {
+            locale.to_string()
+        } else {
+            format!("{}.UTF-8", locale)
+        };
+
+        self.env.retain(|(key, _)| key != "LANG" && key != "LC_ALL");
+        self.env.push(("LANG".to_string(), locale.clone()));
+        self.env.push(("LC_ALL".to_string(), locale));
+        self
+    }
+
+    /// Output charset implied by the locale env ("UTF-8" if unspecified)
+    ///
+    /// LC_ALL takes precedence over LANG, matching libc.
+    pub fn charset(&self) -> String {
+        let lookup = |name: &str| {
+            self.env.iter().rev().find(|(key, _)| key == name).map(|(_, value)| value.as_str())
+        };
+
+        lookup("LC_ALL")
+            .or_else(|| lookup("LANG"))
+            .and_then(|locale| locale.split('.').nth(1))
+            .map(|charset| charset.split('@').next().unwrap_or(charset).to_string())
+            .filter(|charset| !charset.is_empty())
+            .unwrap_or_else(|| "UTF-8".to_string())
+    }
 }
 
 /// Mock terminal for testing
@@ -210,6 +250,33 @@ mod tests {
         assert_eq!(config.env.len(), 4);
     }
 
+    #[test]
+    fn test_with_locale_applied_to_env() {
+        let config = TerminalConfig::default().with_locale("vi_VN.UTF-8");
+        let lang: Vec<_> = config.env.iter().filter(|(k, _)| k == "LANG").collect();
+        let lc_all: Vec<_> = config.env.iter().filter(|(k, _)| k == "LC_ALL").collect();
+        assert_eq!(lang, vec![&("LANG".to_string(), "vi_VN.UTF-8".to_string())]);
+        assert_eq!(lc_all, vec![&("LC_ALL".to_string(), "vi_VN.UTF-8".to_string())]);
+        assert_eq!(config.charset(), "UTF-8");
+    }
+
+    #[test]
+    fn test_with_locale_defaults_to_utf8() {
+        let config = TerminalConfig::default().with_locale("ja_JP");
+        assert!(config.env.contains(&("LANG".to_string(), "ja_JP.UTF-8".to_string())));
+        assert_eq!(config.charset(), "UTF-8");
+
+        let config = TerminalConfig::default().with_locale("ja_JP.EUC-JP");
+        assert_eq!(config.charset(), "EUC-JP");
+    }
+
+    #[test]
+    fn test_with_locale_rejects_invalid() {
+        let config = TerminalConfig::default().with_locale("en_US.UTF-8");
+        let unchanged = config.clone().with_locale("x; rm -rf /");
+        assert_eq!(unchanged.env, config.env);
+    }
+
     #[tokio::test]
     async fn test_get_snapshot() {
         let mut term = MockTerminal::new(TerminalConfig::default());
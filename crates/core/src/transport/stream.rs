@@ -4,7 +4,9 @@
 //! It uses Quinn's built-in flow control for natural backpressure.
 
 use quinn::{RecvStream, SendStream};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::sync::Mutex;
 
@@ -12,6 +14,39 @@ use crate::protocol::MessageCodec;
 use crate::types::{NetworkMessage, TerminalEvent, TaggedOutput};
 use crate::{CoreError, Result};
 
+/// Shared round-trip time estimate for adaptive output batching
+///
+/// Fed from the QUIC connection's own RTT (`quinn::Connection::rtt()`),
+/// smoothed like TCP SRTT (EWMA, alpha = 1/8). Cheap to clone; all clones
+/// share the same atomic.
+#[derive(Debug, Clone, Default)]
+pub struct RttEstimator {
+    /// Smoothed RTT in milliseconds (0 = no samples yet)
+    srtt_ms: Arc<AtomicU64>,
+}
+
+impl RttEstimator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a measured round trip
+    pub fn record_sample(&self, rtt: Duration) {
+        let sample = (rtt.as_millis() as u64).max(1);
+        let _ = self.srtt_ms.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |srtt| {
+            Some(if srtt == 0 { sample } else { (srtt * 7 + sample) / 8 })
+        });
+    }
+
+    /// Current smoothed RTT, None until the first sample
+    pub fn rtt(&self) -> Option<Duration> {
+        match self.srtt_ms.load(Ordering::Relaxed) {
+            0 => None,
+            ms => Some(Duration::from_millis(ms)),
+        }
+    }
+}
+
 /// Smart buffering configuration for PTY→QUIC streaming
 ///
 /// Balances latency (interactive typing) vs throughput (bulk output).
@@ -21,6 +56,7 @@ pub struct BufferConfig {
     pub max_batch_size: usize,
 
     /// Maximum time to wait before flushing (milliseconds)
+    /// With an RTT estimate, used until the first sample arrives
     pub max_flush_delay_ms: u64,
 
     /// Flush immediately on newline (for interactive mode)
@@ -57,6 +93,84 @@ impl BufferConfig {
             flush_on_newline: false,
         }
     }
+
+    /// Adaptive mode: base settings for `pump_pty_to_quic_adaptive`
+    /// Best for: mobile links where latency varies (WiFi ↔ cellular)
+    ///
+    /// High RTT → longer delay, fewer and bigger messages to amortize latency.
+    /// Low RTT → short delay for snappy typing.
+    pub fn adaptive() -> Self {
+        Self {
+            max_batch_size: 32 * 1024,  // 32KB
+            max_flush_delay_ms: 10,     // Until first RTT sample
+            flush_on_newline: true,
+        }
+    }
+
+    /// Effective flush delay for the given RTT
+    ///
+    /// A quarter of the RTT, clamped to 2..=100ms; `max_flush_delay_ms`
+    /// without an RTT.
+    pub fn flush_delay(&self, rtt: Option<Duration>) -> Duration {
+        match rtt {
+            Some(rtt) => (rtt / 4).clamp(Duration::from_millis(2), Duration::from_millis(100)),
+            None => Duration::from_millis(self.max_flush_delay_ms),
+        }
+    }
+
+    /// Whether to flush on newline for the given RTT
+    ///
+    /// Disabled on high-RTT links (>= 100ms) where each extra message costs
+    /// more than the coalescing delay.
+    pub fn should_flush_on_newline(&self, rtt: Option<Duration>) -> bool {
+        match rtt {
+            Some(rtt) => self.flush_on_newline && rtt < Duration::from_millis(100),
+            None => self.flush_on_newline,
+        }
+    }
+}
+
+/// Max bytes buffered for a line without newline before it is emitted anyway
+const MAX_PENDING_LINE: usize = 10_000;
+
+/// Splits PTY output into history lines
+///
+/// Splits on raw `\n` bytes, so multi-byte UTF-8 characters and ANSI escape
+/// sequences (neither ever contains `\n`) stay intact across chunk boundaries.
+/// Lines are decoded lossily: invalid UTF-8 becomes U+FFFD instead of
+/// stalling capture or panicking.
+#[derive(Debug, Default)]
+pub struct HistoryLineSplitter {
+    /// Bytes of the current incomplete line
+    pending: Vec<u8>,
+}
+
+impl HistoryLineSplitter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed a chunk of PTY output, returning completed lines (without `\n`)
+    pub fn push(&mut self, data: &[u8]) -> Vec<String> {
+        self.pending.extend_from_slice(data);
+
+        let mut lines = Vec::new();
+        let mut start = 0;
+        while let Some(pos) = self.pending[start..].iter().position(|&b| b == b'\n') {
+            let end = start + pos;
+            lines.push(String::from_utf8_lossy(&self.pending[start..end]).into_owned());
+            start = end + 1;
+        }
+        self.pending.drain(..start);
+
+        // Safety: Prevent unbounded growth (progress bars, binary garbage)
+        if self.pending.len() > MAX_PENDING_LINE {
+            lines.push(String::from_utf8_lossy(&self.pending).into_owned());
+            self.pending.clear();
+        }
+
+        lines
+    }
 }
 
 /// Pump data from PTY to QUIC stream
@@ -120,23 +234,74 @@ where
 ///
 /// # Arguments
 /// * `pty` - Async reader from PTY
-/// * `send` - QUIC send stream
+/// * `send` - Shared QUIC send stream, locked per flush
 /// * `config` - Buffering strategy
 pub async fn pump_pty_to_quic_smart<R>(
+    pty: R,
+    send: &Mutex<SendStream>,
+    config: BufferConfig,
+) -> Result<()>
+where
+    R: AsyncReadExt + Unpin + Send,
+{
+    pump_batched(pty, send, config, None, output_event).await
+}
+
+/// Pump data from PTY to QUIC stream with RTT-adaptive buffering
+///
+/// Same batching as `pump_pty_to_quic_smart`, but the flush delay and
+/// flush-on-newline follow the current RTT estimate (see
+/// `BufferConfig::flush_delay`). Falls back to `config` until the first sample.
+///
+/// # Arguments
+/// * `pty` - Async reader from PTY
+/// * `send` - Shared QUIC send stream, locked per flush so other replies
+///   (completion, file reads, events) can interleave with output
+/// * `config` - Base buffering strategy (usually `BufferConfig::adaptive()`)
+/// * `rtt` - RTT estimate, updated by the caller while the pump runs
+pub async fn pump_pty_to_quic_adaptive<R>(
+    pty: R,
+    send: &Mutex<SendStream>,
+    config: BufferConfig,
+    rtt: &RttEstimator,
+) -> Result<()>
+where
+    R: AsyncReadExt + Unpin + Send,
+{
+    pump_batched(pty, send, config, Some(rtt), output_event).await
+}
+
+/// Wrap a batch as a plain (untagged) output event
+fn output_event(data: &[u8]) -> NetworkMessage {
+    NetworkMessage::Event(TerminalEvent::Output {
+        data: data.to_vec(),
+    })
+}
+
+/// Batching loop shared by the smart, adaptive and tagged pumps
+///
+/// `wrap` turns each flushed batch into the message to send. The send
+/// stream is only locked while a batch is written.
+async fn pump_batched<R, F>(
     mut pty: R,
-    send: &mut SendStream,
+    send: &Mutex<SendStream>,
     config: BufferConfig,
+    rtt: Option<&RttEstimator>,
+    mut wrap: F,
 ) -> Result<()>
 where
     R: AsyncReadExt + Unpin + Send,
+    F: FnMut(&[u8]) -> NetworkMessage,
 {
     let mut read_buf = vec![0u8; 8192];
     let mut batch_buf = Vec::with_capacity(config.max_batch_size);
 
     loop {
+        let rtt = rtt.and_then(|r| r.rtt());
+
         // Calculate timeout: only flush if we have buffered data
         let flush_timeout = if !batch_buf.is_empty() {
-            std::time::Duration::from_millis(config.max_flush_delay_ms)
+            config.flush_delay(rtt)
         } else {
             // No data buffered, wait indefinitely for new data
             std::time::Duration::from_secs(3600)
@@ -149,7 +314,7 @@ where
                 if n == 0 {
                     // EOF - flush remaining and exit
                     if !batch_buf.is_empty() {
-                        send_batch(&batch_buf, send).await?;
+                        send_batch(&batch_buf, &mut wrap, send).await?;
                     }
                     break;
                 }
@@ -163,13 +328,13 @@ where
                 } else {
                     // Batch full - send current, start new
                     if !batch_buf.is_empty() {
-                        send_batch(&batch_buf, send).await?;
+                        send_batch(&batch_buf, &mut wrap, send).await?;
                     }
                     batch_buf = read_buf[..n].to_vec();
                 }
 
                 // Immediate flush conditions (no waiting)
-                let should_flush = if config.flush_on_newline && chunk_has_newline {
+                let should_flush = if config.should_flush_on_newline(rtt) && chunk_has_newline {
                     true  // Interactive mode - flush on newline
                 } else if batch_buf.len() >= config.max_batch_size {
                     true  // Size threshold - flush to avoid oversized batches
@@ -178,20 +343,20 @@ where
                 };
 
                 if should_flush {
-                    send_batch(&batch_buf, send).await?;
+                    send_batch(&batch_buf, &mut wrap, send).await?;
                     batch_buf.clear();
                 }
             }
 
             // Case 2: Timeout expired - flush buffered data
             _ = tokio::time::sleep(flush_timeout), if !batch_buf.is_empty() => {
-                send_batch(&batch_buf, send).await?;
+                send_batch(&batch_buf, &mut wrap, send).await?;
                 batch_buf.clear();
             }
         }
     }
 
-    let _ = send.finish();
+    let _ = send.lock().await.finish();
     Ok(())
 }
 
@@ -199,93 +364,63 @@ where
 ///
 /// Multi-session variant that wraps output in TaggedOutput for routing.
 /// Also captures output to history buffer for session replay.
+/// Output is batched like `pump_pty_to_quic_adaptive`.
 ///
 /// # Arguments
 /// * `pty` - Async reader from PTY
-/// * `send` - QUIC send stream
+/// * `send` - Shared QUIC send stream, locked per flush so other replies
+///   (completion, file reads, events) can interleave with output
 /// * `session_id` - UUID of the session generating this output
 /// * `history_tx` - Optional channel sender to push history lines (for inactive sessions)
+/// * `config` - Base buffering strategy (usually `BufferConfig::adaptive()`)
+/// * `rtt` - RTT estimate, updated by the caller while the pump runs
 ///
 /// # History Capture
-/// - Splits output by newlines (\n)
-/// - Maintains incomplete UTF-8 sequences between chunks
+/// - Splits output by newlines (\n) via `HistoryLineSplitter`
+/// - Keeps incomplete lines (and split UTF-8 sequences) between chunks
 /// - Max 100 lines in history buffer
 pub async fn pump_pty_to_quic_tagged<R>(
-    mut pty: R,
-    send: &mut SendStream,
+    pty: R,
+    send: &Mutex<SendStream>,
     session_id: String,
     history_tx: Option<tokio::sync::mpsc::Sender<String>>,
+    config: BufferConfig,
+    rtt: &RttEstimator,
 ) -> Result<()>
 where
     R: AsyncReadExt + Unpin + Send,
 {
-    let mut buf = vec![0u8; 8192];
-    let mut line_accumulator = Vec::new(); // For handling split UTF-8
+    let mut splitter = HistoryLineSplitter::new();
 
-    loop {
-        let n = pty.read(&mut buf).await?;
-        if n == 0 {
-            tracing::debug!("PTY EOF for session {}, closing stream", session_id);
-            break;
+    let result = pump_batched(pty, send, config, Some(rtt), |data| {
+        // Capture to history (best effort, non-blocking)
+        if let Some(ref tx) = history_tx {
+            for line in splitter.push(data) {
+                let _ = tx.try_send(line); // Non-blocking, drops if full
+            }
         }
+        tracing::trace!("Sending {} bytes from PTY session {} to QUIC", data.len(), session_id);
 
-        let data = &buf[..n];
-
-        // FAST PATH: Send to network immediately (no waiting for history)
-        let msg = NetworkMessage::TaggedOutput(TaggedOutput {
+        NetworkMessage::TaggedOutput(TaggedOutput {
             session_id: session_id.clone(),
             data: data.to_vec(),
-        });
-        let encoded = MessageCodec::encode(&msg)?;
-        send.write_all(&encoded).await?;
-
-        // SLOW PATH: Capture to history (best effort, non-blocking)
-        if let Some(ref tx) = history_tx {
-            // Accumulate bytes and try to extract complete lines
-            line_accumulator.extend_from_slice(data);
-
-            // Try to parse as UTF-8 and extract lines
-            if let Ok(text) = String::from_utf8(line_accumulator.clone()) {
-                let mut lines = text.split('\n').peekable();
-                let mut has_incomplete = false;
-
-                while let Some(line) = lines.next() {
-                    if lines.peek().is_some() {
-                        // Complete line (before \n)
-                        let _ = tx.try_send(line.to_string()); // Non-blocking, drops if full
-                    } else {
-                        // Last segment (may be incomplete if no trailing \n)
-                        if !text.ends_with('\n') && !line.is_empty() {
-                            line_accumulator = line.as_bytes().to_vec();
-                            has_incomplete = true;
-                        }
-                    }
-                }
-
-                if !has_incomplete {
-                    line_accumulator.clear();
-                }
-            } else {
-                // Invalid UTF-8 - this happens when multi-byte char is split across chunks
-                // Keep the bytes and wait for next chunk to complete the character
-                // Safety: Prevent unbounded growth from binary garbage
-                if line_accumulator.len() > 10000 {
-                    line_accumulator.clear();
-                }
-            }
+        })
+    })
+    .await;
 
-            tracing::trace!("Sent {} bytes from PTY session {} to QUIC (history captured)", n, session_id);
-        } else {
-            tracing::trace!("Sent {} bytes from PTY session {} to QUIC (no history)", n, session_id);
-        }
+    if result.is_ok() {
+        tracing::debug!("PTY EOF for session {}, closing stream", session_id);
     }
-
-    let _ = send.finish();
-    Ok(())
+    result
 }
 
 /// Helper: send a batch of data as a single NetworkMessage
-async fn send_batch(data: &[u8], send: &mut SendStream) -> Result<()> {
+///
+/// Holds the send lock only for this write.
+async fn send_batch<F>(data: &[u8], wrap: &mut F, send: &Mutex<SendStream>) -> Result<()>
+where
+    F: FnMut(&[u8]) -> NetworkMessage,
+{
     if data.is_empty() {
         return Ok(());
     }
@@ -293,11 +428,8 @@ async fn send_batch(data: &[u8], send: &mut SendStream) -> Result<()> {
     // DEBUG: Log PTY output
     eprintln!("[DEBUG] PTY output: {:02X?}", data);
 
-    let msg = NetworkMessage::Event(TerminalEvent::Output {
-        data: data.to_vec(),
-    });
-    let encoded = MessageCodec::encode(&msg)?;
-    send.write_all(&encoded).await?;
+    let encoded = MessageCodec::encode(&wrap(data))?;
+    send.lock().await.write_all(&encoded).await?;
     Ok(())
 }
 
@@ -454,6 +586,186 @@ mod tests {
         assert!(max_size == 16 * 1024 * 1024);
     }
 
-    // Note: Full integration tests require async runtime and mock streams
-    // These are better suited as integration tests in the test suite
+    #[test]
+    fn test_adaptive_flush_delay_grows_with_rtt() {
+        let config = BufferConfig::adaptive();
+        let low = Some(Duration::from_millis(20));
+        let high = Some(Duration::from_millis(300));
+
+        assert!(config.flush_delay(high) > config.flush_delay(low));
+        assert_eq!(config.flush_delay(low), Duration::from_millis(5));
+        assert_eq!(config.flush_delay(high), Duration::from_millis(75));
+        assert!(config.should_flush_on_newline(low));
+        assert!(!config.should_flush_on_newline(high));
+    }
+
+    #[test]
+    fn test_adaptive_without_samples_uses_base_delay() {
+        let config = BufferConfig::adaptive();
+        assert_eq!(config.flush_delay(RttEstimator::new().rtt()), Duration::from_millis(config.max_flush_delay_ms));
+        assert_eq!(BufferConfig::default().flush_delay(None), Duration::from_millis(10));
+    }
+
+    #[test]
+    fn test_rtt_estimator_smoothing() {
+        let rtt = RttEstimator::new();
+        assert_eq!(rtt.rtt(), None);
+
+        rtt.record_sample(Duration::from_millis(80));
+        assert_eq!(rtt.rtt(), Some(Duration::from_millis(80)));
+
+        // One spike moves the estimate by 1/8 of the difference
+        rtt.record_sample(Duration::from_millis(160));
+        assert_eq!(rtt.rtt(), Some(Duration::from_millis(90)));
+    }
+
+    #[test]
+    fn test_history_splitter_keeps_ansi_art_intact() {
+        // Multi-line ANSI art with colors, cursor moves and multi-byte glyphs
+        let art = "\x1b[31m█▀▀█\x1b[0m\r\n\x1b[1;32m█▄▄█ ✓\x1b[0m\r\n\x1b[2K\x1b[10Gdone\n";
+        let bytes = art.as_bytes();
+
+        // Feed one byte at a time: splits inside escapes and UTF-8 sequences
+        let mut splitter = HistoryLineSplitter::new();
+        let lines: Vec<String> = bytes.iter().flat_map(|b| splitter.push(&[*b])).collect();
+
+        assert_eq!(lines, vec![
+            "\x1b[31m█▀▀█\x1b[0m\r".to_string(),
+            "\x1b[1;32m█▄▄█ ✓\x1b[0m\r".to_string(),
+            "\x1b[2K\x1b[10Gdone".to_string(),
+        ]);
+    }
+
+    #[test]
+    fn test_history_splitter_invalid_utf8_does_not_stall() {
+        let mut splitter = HistoryLineSplitter::new();
+        assert!(splitter.push(&[0xff, 0xfe, b'a']).is_empty());
+        assert_eq!(splitter.push(b"\nnext\n"), vec!["\u{fffd}\u{fffd}a".to_string(), "next".to_string()]);
+    }
+
+    #[test]
+    fn test_history_splitter_flushes_overlong_line() {
+        let mut splitter = HistoryLineSplitter::new();
+        let lines = splitter.push(&[b'x'; MAX_PENDING_LINE + 1]);
+        assert_eq!(lines.len(), 1);
+        assert!(splitter.push(b"\n").first().is_some_and(|l| l.is_empty()));
+    }
+
+    /// Endpoints and connections that must outlive a loopback stream
+    type Loopback = (quinn::Endpoint, quinn::Endpoint, quinn::Connection);
+
+    /// Open a loopback QUIC uni stream: (host send side, client connection)
+    ///
+    /// The client accepts the stream once the host has written to it.
+    async fn loopback_stream() -> (SendStream, quinn::Connection, Loopback) {
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let cert = rcgen::generate_simple_self_signed(["localhost".to_string()]).unwrap();
+        let cert_der = rustls::pki_types::CertificateDer::from(cert.cert);
+        let key_der = rustls::pki_types::PrivateKeyDer::Pkcs8(
+            rustls::pki_types::PrivatePkcs8KeyDer::from(cert.key_pair.serialize_der())
+        );
+
+        let mut roots = rustls::RootCertStore::empty();
+        roots.add(cert_der.clone()).unwrap();
+        let client_crypto = rustls::ClientConfig::builder()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        let client_config = crate::transport::configure_client(Arc::new(
+            quinn::crypto::rustls::QuicClientConfig::try_from(client_crypto).unwrap(),
+        ));
+
+        let server_config = crate::transport::configure_server(vec![cert_der], key_der).unwrap();
+        let server = quinn::Endpoint::server(server_config, "127.0.0.1:0".parse().unwrap()).unwrap();
+        let mut client = quinn::Endpoint::client("127.0.0.1:0".parse().unwrap()).unwrap();
+        client.set_default_client_config(client_config);
+
+        let addr = server.local_addr().unwrap();
+        let (client_conn, server_conn) = tokio::join!(
+            async { client.connect(addr, "localhost").unwrap().await.unwrap() },
+            async { server.accept().await.unwrap().await.unwrap() },
+        );
+        let send = server_conn.open_uni().await.unwrap();
+
+        (send, client_conn, (server, client, server_conn))
+    }
+
+    /// Read one length-prefixed message from the client side
+    async fn read_message(recv: &mut RecvStream) -> NetworkMessage {
+        let mut len_buf = [0u8; 4];
+        recv.read_exact(&mut len_buf).await.unwrap();
+        let mut buf = len_buf.to_vec();
+        buf.resize(4 + u32::from_be_bytes(len_buf) as usize, 0);
+        recv.read_exact(&mut buf[4..]).await.unwrap();
+        MessageCodec::decode(&buf).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_adaptive_pump_releases_send_lock_between_flushes() {
+        let (send, client_conn, _loopback) = loopback_stream().await;
+        let send = Arc::new(Mutex::new(send));
+        let (mut pty_in, pty_out) = tokio::io::duplex(1024);
+
+        let pump = tokio::spawn({
+            let send = send.clone();
+            async move { pump_pty_to_quic_adaptive(pty_out, &send, BufferConfig::adaptive(), &RttEstimator::new()).await }
+        });
+
+        pty_in.write_all(b"ls\n").await.unwrap();
+        let mut recv = client_conn.accept_uni().await.unwrap();
+        assert_eq!(
+            read_message(&mut recv).await,
+            NetworkMessage::Event(TerminalEvent::Output { data: b"ls\n".to_vec() })
+        );
+
+        // Pump is still running, but other replies can take the stream
+        let mut send_lock = tokio::time::timeout(Duration::from_secs(1), send.lock())
+            .await
+            .expect("pump must not hold the send lock while idle");
+        send_lock.write_all(&MessageCodec::encode(&NetworkMessage::Close).unwrap()).await.unwrap();
+        drop(send_lock);
+        assert_eq!(read_message(&mut recv).await, NetworkMessage::Close);
+
+        drop(pty_in);
+        pump.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_tagged_pump_batches_by_rtt() {
+        let (send, client_conn, _loopback) = loopback_stream().await;
+        let send = Mutex::new(send);
+        let (mut pty_in, pty_out) = tokio::io::duplex(1024);
+        let (history_tx, mut history_rx) = tokio::sync::mpsc::channel(10);
+
+        // High RTT: 100ms flush delay and no flush on newline
+        let rtt = RttEstimator::new();
+        rtt.record_sample(Duration::from_millis(400));
+
+        let pump = pump_pty_to_quic_tagged(
+            pty_out,
+            &send,
+            "s1".to_string(),
+            Some(history_tx),
+            BufferConfig::adaptive(),
+            &rtt,
+        );
+        let client = async {
+            pty_in.write_all(b"a\n").await.unwrap();
+            pty_in.write_all(b"b\n").await.unwrap();
+
+            let mut recv = client_conn.accept_uni().await.unwrap();
+            let msg = read_message(&mut recv).await;
+            drop(pty_in);
+            msg
+        };
+        let (result, msg) = tokio::join!(pump, client);
+        result.unwrap();
+
+        assert_eq!(msg, NetworkMessage::TaggedOutput(TaggedOutput {
+            session_id: "s1".to_string(),
+            data: b"a\nb\n".to_vec(),
+        }));
+        assert_eq!(history_rx.recv().await.as_deref(), Some("a"));
+        assert_eq!(history_rx.recv().await.as_deref(), Some("b"));
+    }
 }
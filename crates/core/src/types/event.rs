@@ -20,7 +20,8 @@ pub enum TerminalEvent {
     // ===== Multi-Session Events - Phase 04 =====
 
     /// Session created successfully
-    SessionCreated { session_id: String },
+    /// `charset` is the PTY output encoding the renderer should expect
+    SessionCreated { session_id: String, charset: String },
 
     /// Session exists and can be re-attached
     SessionReAttach { session_id: String },
@@ -66,8 +67,8 @@ impl TerminalEvent {
     // ===== Session event helpers - Phase 04 =====
 
     /// Create session created event
-    pub fn session_created(session_id: String) -> Self {
-        Self::SessionCreated { session_id }
+    pub fn session_created(session_id: String, charset: String) -> Self {
+        Self::SessionCreated { session_id, charset }
     }
 
     /// Create session re-attach event
@@ -5,6 +5,9 @@ use crate::{AuthToken, CoreError, PROTOCOL_VERSION, APP_VERSION_STRING, Result};
 use super::{TerminalCommand, TerminalEvent};
 
 /// Network message type for QUIC protocol
+///
+/// Postcard encodes variants by position: add new variants at the end, and
+/// bump `PROTOCOL_VERSION` for any other wire change.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum NetworkMessage {
     /// Protocol handshake
@@ -49,6 +52,8 @@ pub enum NetworkMessage {
         shell: Option<String>,
         /// Optional: additional env vars
         env: Vec<(String, String)>,
+        /// Optional locale for LANG/LC_ALL, same as in CreateSession
+        locale: Option<String>,
     },
 
     /// Explicit shell start command (SSH-like protocol)
@@ -149,6 +154,85 @@ pub enum NetworkMessage {
         session_id: String,
         lines: Vec<String>,
     },
+
+    // ===== Command Completion =====
+
+    /// Request completions for the token under the cursor
+    /// Server resolves paths relative to the session's working directory
+    CompleteRequest {
+        session_id: String,
+        line: String,
+        /// Cursor position in characters (not bytes)
+        cursor: usize,
+    },
+
+    /// Completion suggestions (full replacement for the current token)
+    CompleteResult {
+        suggestions: Vec<String>,
+    },
+
+    /// Pause or resume terminal output (client → host)
+    /// Sent when the mobile app is backgrounded/foregrounded.
+    /// While paused, host holds PTY output instead of sending it.
+    /// Host echoes the message back as acknowledgement.
+    FlowControl { paused: bool },
+
+    // ===== VFS Chunked File Reading =====
+
+    /// Request chunked file read (for large downloads)
+    /// Server responds with one or more FileChunk messages on a new
+    /// unidirectional stream, so a slow download never stalls the terminal
+    ReadFileChunked {
+        path: String,
+        chunk_size: u32,  // Bytes per chunk (clamped by server)
+        /// Echoed in every FileChunk so chunks of older downloads can be told apart
+        request_id: u32,
+    },
+
+    /// File data chunk (part of ReadFileChunked response)
+    FileChunk {
+        path: String,
+        chunk_index: u32,
+        offset: u64,
+        data: Vec<u8>,
+        /// On-disk size captured at open; only set on the first chunk
+        total_size: Option<u64>,
+        has_more: bool,
+        /// `request_id` of the ReadFileChunked this chunk answers
+        request_id: u32,
+    },
+
+    // ===== VFS File Writing =====
+
+    /// Request to write a whole file
+    /// Server writes to a temp file and renames, so the target is untouched on failure
+    WriteFile {
+        path: String,
+        data: Vec<u8>,
+    },
+
+    /// Write data at offset (for chunked uploads)
+    /// File is created if missing; existing content outside the range is kept
+    WriteChunk {
+        path: String,
+        offset: u64,
+        data: Vec<u8>,
+    },
+
+    /// Write response (for WriteFile and WriteChunk)
+    WriteResult {
+        path: String,
+        bytes_written: u64,  // Bytes actually written before any error
+        error: Option<WriteError>,
+    },
+
+    // ===== Shell Selection =====
+
+    /// Request list of shells installed on the host
+    QueryShells,
+
+    /// Installed shells (response to QueryShells)
+    ShellList { shells: Vec<String> },
 }
 
 /// Tagged output for multi-session routing
@@ -170,6 +254,11 @@ pub enum SessionMessage {
     CreateSession {
         project_path: String,
         session_id: String,
+        /// Optional locale for LANG/LC_ALL (e.g. "vi_VN.UTF-8")
+        /// None = host default; charset defaults to UTF-8 if omitted
+        locale: Option<String>,
+        /// Optional shell path (one of ShellList, others are rejected); None = host default
+        shell: Option<String>,
     },
 
     /// Check if session exists (for re-attach on app restart)
@@ -212,6 +301,30 @@ pub enum FileEventType {
     Renamed { old_name: String },
 }
 
+/// Write failure reported in WriteResult
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum WriteError {
+    /// No space left on device (or quota exceeded)
+    DiskFull,
+    /// Write stopped early; `bytes_written` in WriteResult is less than expected
+    WritePartial { expected: u64 },
+    /// Target or its directory is not writable
+    PermissionDenied,
+    /// Any other I/O error
+    Io(String),
+}
+
+impl std::fmt::Display for WriteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WriteError::DiskFull => write!(f, "Disk full"),
+            WriteError::WritePartial { expected } => write!(f, "Partial write (expected {} bytes)", expected),
+            WriteError::PermissionDenied => write!(f, "Permission denied"),
+            WriteError::Io(e) => write!(f, "I/O error: {}", e),
+        }
+    }
+}
+
 impl NetworkMessage {
     /// Create hello message
     /// Phase E03: Takes optional auth token
@@ -268,6 +381,7 @@ impl NetworkMessage {
             cols,
             shell: None,
             env: vec![],
+            locale: None,
         }
     }
 
@@ -278,6 +392,18 @@ impl NetworkMessage {
             cols,
             shell,
             env,
+            locale: None,
+        }
+    }
+
+    /// Create RequestPty message with a session locale (e.g. "vi_VN.UTF-8")
+    pub fn request_pty_with_locale(rows: u16, cols: u16, locale: String) -> Self {
+        Self::RequestPty {
+            rows,
+            cols,
+            shell: None,
+            env: vec![],
+            locale: Some(locale),
         }
     }
 
@@ -296,6 +422,16 @@ impl NetworkMessage {
         Self::Snapshot { data, rows, cols }
     }
 
+    /// Create FlowControl message that pauses output
+    pub fn pause_output() -> Self {
+        Self::FlowControl { paused: true }
+    }
+
+    /// Create FlowControl message that resumes output
+    pub fn resume_output() -> Self {
+        Self::FlowControl { paused: false }
+    }
+
     /// Create ReadFile message
     pub fn read_file(path: String, max_size: usize) -> Self {
         Self::ReadFile { path, max_size }
@@ -305,6 +441,31 @@ impl NetworkMessage {
     pub fn file_content(path: String, content: String, size: usize, truncated: bool) -> Self {
         Self::FileContent { path, content, size, truncated }
     }
+
+    /// Create ReadFileChunked message
+    pub fn read_file_chunked(path: String, chunk_size: u32, request_id: u32) -> Self {
+        Self::ReadFileChunked { path, chunk_size, request_id }
+    }
+
+    /// Create WriteFile message
+    pub fn write_file(path: String, data: Vec<u8>) -> Self {
+        Self::WriteFile { path, data }
+    }
+
+    /// Create WriteChunk message
+    pub fn write_chunk(path: String, offset: u64, data: Vec<u8>) -> Self {
+        Self::WriteChunk { path, offset, data }
+    }
+
+    /// Create CompleteRequest message
+    pub fn complete_request(session_id: String, line: String, cursor: usize) -> Self {
+        Self::CompleteRequest { session_id, line, cursor }
+    }
+
+    /// Create CompleteResult response
+    pub fn complete_result(suggestions: Vec<String>) -> Self {
+        Self::CompleteResult { suggestions }
+    }
 }
 
 #[cfg(test)]
@@ -359,7 +520,7 @@ mod tests {
         assert!(result.is_err());
         match result.unwrap_err() {
             CoreError::ProtocolVersionMismatch { expected, got } => {
-                assert_eq!(expected, 1);
+                assert_eq!(expected, crate::PROTOCOL_VERSION);
                 assert_eq!(got, 999);
             }
             _ => panic!("Expected ProtocolVersionMismatch error"),
@@ -433,4 +594,153 @@ mod tests {
         let deserialized: NetworkMessage = postcard::from_bytes(&serialized).unwrap();
         assert_eq!(msg, deserialized);
     }
+
+    #[test]
+    fn test_wire_encoding_pinned() {
+        // Fixed bytes: a reordered variant or changed field breaks this test
+        // instead of silently breaking older peers
+        let cases = vec![
+            (
+                NetworkMessage::Hello {
+                    protocol_version: 2,
+                    app_version: "x".to_string(),
+                    capabilities: 0,
+                    auth_token: None,
+                },
+                vec![0, 2, 1, b'x', 0, 0],
+            ),
+            (NetworkMessage::Ping { timestamp: 5 }, vec![4, 5]),
+            (NetworkMessage::Pong { timestamp: 5 }, vec![5, 5]),
+            (NetworkMessage::Resize { rows: 24, cols: 80 }, vec![6, 24, 80]),
+            (NetworkMessage::StartShell, vec![8]),
+            (NetworkMessage::RequestSnapshot, vec![9]),
+            (NetworkMessage::Close, vec![11]),
+            (NetworkMessage::ListDir { path: "a".to_string(), depth: None }, vec![12, 1, b'a', 0]),
+            (
+                NetworkMessage::DirChunk { chunk_index: 0, total_chunks: 1, entries: vec![], has_more: false },
+                vec![13, 0, 1, 0, 0],
+            ),
+            (NetworkMessage::WatchDir { path: "a".to_string() }, vec![14, 1, b'a']),
+            (NetworkMessage::WatchStarted { watcher_id: "w".to_string() }, vec![15, 1, b'w']),
+            (
+                NetworkMessage::FileEvent {
+                    watcher_id: "w".to_string(),
+                    path: "a".to_string(),
+                    event_type: FileEventType::Created,
+                    timestamp: 7,
+                },
+                vec![16, 1, b'w', 1, b'a', 0, 7],
+            ),
+            (NetworkMessage::UnwatchDir { watcher_id: "w".to_string() }, vec![17, 1, b'w']),
+            (
+                NetworkMessage::WatchError { watcher_id: "w".to_string(), error: "e".to_string() },
+                vec![18, 1, b'w', 1, b'e'],
+            ),
+            (NetworkMessage::ReadFile { path: "a".to_string(), max_size: 10 }, vec![19, 1, b'a', 10]),
+            (
+                NetworkMessage::FileContent {
+                    path: "a".to_string(),
+                    content: "b".to_string(),
+                    size: 1,
+                    truncated: false,
+                },
+                vec![20, 1, b'a', 1, b'b', 1, 0],
+            ),
+            (
+                NetworkMessage::TaggedOutput(TaggedOutput { session_id: "s".to_string(), data: vec![1] }),
+                vec![21, 1, b's', 1, 1],
+            ),
+            (
+                NetworkMessage::Session(SessionMessage::CreateSession {
+                    project_path: "p".to_string(),
+                    session_id: "s".to_string(),
+                    locale: None,
+                    shell: None,
+                }),
+                vec![22, 0, 1, b'p', 1, b's', 0, 0],
+            ),
+            (NetworkMessage::Session(SessionMessage::ListSessions), vec![22, 4]),
+            (
+                NetworkMessage::SessionHistory { session_id: "s".to_string(), lines: vec![] },
+                vec![23, 1, b's', 0],
+            ),
+            (
+                NetworkMessage::CompleteRequest { session_id: "s".to_string(), line: "l".to_string(), cursor: 1 },
+                vec![24, 1, b's', 1, b'l', 1],
+            ),
+            (NetworkMessage::CompleteResult { suggestions: vec![] }, vec![25, 0]),
+            (NetworkMessage::FlowControl { paused: true }, vec![26, 1]),
+            (
+                NetworkMessage::ReadFileChunked { path: "a".to_string(), chunk_size: 5, request_id: 7 },
+                vec![27, 1, b'a', 5, 7],
+            ),
+            (
+                NetworkMessage::FileChunk {
+                    path: "a".to_string(),
+                    chunk_index: 0,
+                    offset: 0,
+                    data: vec![1],
+                    total_size: Some(1),
+                    has_more: false,
+                    request_id: 7,
+                },
+                vec![28, 1, b'a', 0, 0, 1, 1, 1, 1, 0, 7],
+            ),
+            (NetworkMessage::WriteFile { path: "a".to_string(), data: vec![1] }, vec![29, 1, b'a', 1, 1]),
+            (
+                NetworkMessage::WriteChunk { path: "a".to_string(), offset: 2, data: vec![1] },
+                vec![30, 1, b'a', 2, 1, 1],
+            ),
+            (
+                NetworkMessage::WriteResult {
+                    path: "a".to_string(),
+                    bytes_written: 0,
+                    error: Some(WriteError::DiskFull),
+                },
+                vec![31, 1, b'a', 0, 1, 0],
+            ),
+            (NetworkMessage::QueryShells, vec![32]),
+            (NetworkMessage::ShellList { shells: vec![] }, vec![33, 0]),
+        ];
+
+        for (msg, bytes) in cases {
+            assert_eq!(postcard::to_allocvec(&msg).unwrap(), bytes, "{:?}", msg);
+        }
+    }
+
+    #[test]
+    fn test_complete_messages_roundtrip() {
+        let req = NetworkMessage::complete_request("abc".to_string(), "cat src/ma".to_string(), 10);
+        let serialized = postcard::to_allocvec(&req).unwrap();
+        let deserialized: NetworkMessage = postcard::from_bytes(&serialized).unwrap();
+        assert_eq!(req, deserialized);
+
+        let res = NetworkMessage::complete_result(vec!["src/main.rs".to_string()]);
+        let serialized = postcard::to_allocvec(&res).unwrap();
+        let deserialized: NetworkMessage = postcard::from_bytes(&serialized).unwrap();
+        assert_eq!(res, deserialized);
+    }
+
+    #[test]
+    fn test_session_history_with_escape_sequences_roundtrip() {
+        let lines = vec![
+            "\x1b[31mred\x1b[0m".to_string(),
+            "\x1b]0;title\x07\x1b[?25l\r".to_string(),
+            "\x1b[1;32m█▄▄█ ✓\x1b[0m".to_string(),
+            "\u{fffd}\t\x00\x08".to_string(),
+            String::new(),
+        ];
+        let msg = NetworkMessage::SessionHistory {
+            session_id: "abc".to_string(),
+            lines: lines.clone(),
+        };
+
+        let encoded = crate::MessageCodec::encode(&msg).unwrap();
+        let decoded = crate::MessageCodec::decode(&encoded).unwrap();
+        assert_eq!(decoded, msg);
+        match decoded {
+            NetworkMessage::SessionHistory { lines: decoded_lines, .. } => assert_eq!(decoded_lines, lines),
+            _ => panic!("Expected SessionHistory"),
+        }
+    }
 }
@@ -4,9 +4,13 @@
 //!
 //! QrPayload contains connection information encoded as QR code
 //! for mobile clients to scan and establish initial trust.
+//!
+//! Payloads can also be exported as a signed envelope (Ed25519) so devices
+//! can verify the pairing blob was issued by a trusted host.
 
 use crate::error::{CoreError, Result};
 use crate::PROTOCOL_VERSION;
+use ring::signature::{Ed25519KeyPair, UnparsedPublicKey, ED25519};
 use serde::{Deserialize, Serialize};
 
 /// QR code payload for pairing
@@ -28,6 +32,17 @@ pub struct QrPayload {
     pub protocol_version: u32,
 }
 
+/// Signed pairing blob
+///
+/// `payload` holds the exact JSON that was signed, so verification does not
+/// depend on field order after re-serialization.
+#[derive(Debug, Serialize, Deserialize)]
+struct SignedEnvelope {
+    payload: String,
+    /// Ed25519 signature over `payload` (hex format)
+    signature: String,
+}
+
 impl QrPayload {
     /// Create new QR payload
     pub fn new(ip: String, port: u16, fingerprint: String, token: String) -> Self {
@@ -52,6 +67,40 @@ impl QrPayload {
             .map_err(|e| CoreError::Protocol(format!("JSON deserialization failed: {}", e)))
     }
 
+    /// Serialize to signed JSON envelope (tamper-evident pairing blob)
+    ///
+    /// Signs the payload JSON with the host's Ed25519 key.
+    /// Unsigned `to_json` is still supported for existing clients.
+    pub fn to_signed(&self, key: &Ed25519KeyPair) -> Result<String> {
+        let payload = self.to_json()?;
+        let signature = key.sign(payload.as_bytes());
+
+        let envelope = SignedEnvelope {
+            payload,
+            signature: signature.as_ref().iter().map(|b| format!("{:02x}", b)).collect(),
+        };
+        serde_json::to_string(&envelope)
+            .map_err(|e| CoreError::Protocol(format!("JSON serialization failed: {}", e)))
+    }
+
+    /// Deserialize signed envelope, verifying it against host public key
+    ///
+    /// # Errors
+    /// - `InvalidSignature` if the payload or signature was tampered with,
+    ///   or was signed by a different key
+    pub fn from_signed(signed: &str, public_key: &[u8]) -> Result<Self> {
+        let envelope: SignedEnvelope = serde_json::from_str(signed)
+            .map_err(|e| CoreError::Protocol(format!("JSON deserialization failed: {}", e)))?;
+
+        let signature = decode_hex(&envelope.signature).ok_or(CoreError::InvalidSignature)?;
+
+        UnparsedPublicKey::new(&ED25519, public_key)
+            .verify(envelope.payload.as_bytes(), &signature)
+            .map_err(|_| CoreError::InvalidSignature)?;
+
+        Self::from_json(&envelope.payload)
+    }
+
     /// Render QR code as Unicode string (for terminal display)
     ///
     /// **IMPORTANT**: Uses Dense1x2 Unicode renderer for terminal.
@@ -90,9 +139,36 @@ impl QrPayload {
     }
 }
 
+/// Decode hex string to bytes, returns None on invalid input
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ring::signature::KeyPair;
+
+    fn test_key_pair() -> Ed25519KeyPair {
+        let rng = ring::rand::SystemRandom::new();
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+        Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap()
+    }
+
+    fn test_payload() -> QrPayload {
+        QrPayload::new(
+            "192.168.1.1".to_string(),
+            8443,
+            "AA:BB:CC:DD".to_string(),
+            "deadbeef".to_string(),
+        )
+    }
 
     #[test]
     fn test_qr_payload_creation() {
@@ -176,4 +252,54 @@ mod tests {
         // Missing required fields should fail
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_qr_payload_signed_roundtrip() {
+        let key = test_key_pair();
+        let signed = test_payload().to_signed(&key).unwrap();
+
+        let decoded = QrPayload::from_signed(&signed, key.public_key().as_ref()).unwrap();
+        assert_eq!(decoded.ip, "192.168.1.1");
+        assert_eq!(decoded.port, 8443);
+        assert_eq!(decoded.fingerprint, "AA:BB:CC:DD");
+        assert_eq!(decoded.token, "deadbeef");
+    }
+
+    #[test]
+    fn test_qr_payload_signed_tampered_payload() {
+        let key = test_key_pair();
+        let signed = test_payload().to_signed(&key).unwrap();
+
+        // Substitute a malicious host IP, keep original signature
+        let tampered = signed.replace("192.168.1.1", "10.6.6.6");
+        assert_ne!(tampered, signed);
+
+        let result = QrPayload::from_signed(&tampered, key.public_key().as_ref());
+        assert!(matches!(result, Err(CoreError::InvalidSignature)));
+    }
+
+    #[test]
+    fn test_qr_payload_signed_wrong_key() {
+        let key = test_key_pair();
+        let other_key = test_key_pair();
+        let signed = test_payload().to_signed(&key).unwrap();
+
+        let result = QrPayload::from_signed(&signed, other_key.public_key().as_ref());
+        assert!(matches!(result, Err(CoreError::InvalidSignature)));
+    }
+
+    #[test]
+    fn test_qr_payload_signed_malformed_signature() {
+        let key = test_key_pair();
+        let signed = test_payload().to_signed(&key).unwrap();
+        let envelope: SignedEnvelope = serde_json::from_str(&signed).unwrap();
+
+        let bad = serde_json::to_string(&SignedEnvelope {
+            payload: envelope.payload,
+            signature: "zz".to_string(),
+        }).unwrap();
+
+        let result = QrPayload::from_signed(&bad, key.public_key().as_ref());
+        assert!(matches!(result, Err(CoreError::InvalidSignature)));
+    }
 }
@@ -12,6 +12,7 @@ mod pty;
 mod quic_server;
 mod ratelimit;
 mod session;
+mod shells;
 mod snapshot;
 mod vfs;
 mod vfs_watcher;
@@ -83,6 +84,7 @@ async fn main() -> Result<()> {
 
     // Create and run QUIC server with auth stores
     let (mut server, cert, _key) = quic_server::QuicServer::new(bind_addr, token_store, rate_limiter).await?;
+    server.set_event_sink(Arc::new(comacode_core::events::TracingEventSink));
 
     // Get certificate fingerprint for QR code
     let cert_fingerprint = crate::cert::CertStore::fingerprint_from_cert_der(&cert);
@@ -6,12 +6,28 @@
 use anyhow::{Context, Result};
 use bytes::Bytes;
 use comacode_core::terminal::TerminalConfig;
-use comacode_core::OutputStream;
+use comacode_core::{CoreError, OutputStream};
 use portable_pty::{native_pty_system, CommandBuilder, PtySize};
 use std::io::{Read, Write};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
+/// Map an `openpty` failure to the error reported to the client
+///
+/// Only ENOENT/ENXIO mean there is no PTY device at all (e.g. a container
+/// without /dev/pts) and get the `PtyUnavailable` hint. Anything else, such
+/// as fd exhaustion, stays a generic error.
+pub(crate) fn openpty_error(err: anyhow::Error, os_error: Option<i32>) -> anyhow::Error {
+    #[cfg(unix)]
+    if matches!(os_error, Some(libc::ENOENT) | Some(libc::ENXIO)) {
+        return CoreError::PtyUnavailable { detail: err.to_string() }.into();
+    }
+    #[cfg(not(unix))]
+    let _ = os_error;
+
+    err.context("Failed to open PTY")
+}
+
 /// PTY session wrapper
 pub struct PtySession {
     /// PTY master handle
@@ -50,9 +66,12 @@ impl PtySession {
             pixel_height: 0,
         };
 
-        let pty_pair = pty_system
-            .openpty(pty_size)
-            .context("Failed to open PTY")?;
+        // portable-pty only keeps the OS error as text, so read errno while it
+        // is still set from the failed openpty call
+        let pty_pair = pty_system.openpty(pty_size).map_err(|e| {
+            let os_error = std::io::Error::last_os_error().raw_os_error();
+            openpty_error(e, os_error)
+        })?;
 
         // Build command with shell and env
         let mut cmd = CommandBuilder::new(config.shell.clone());
@@ -226,3 +245,37 @@ impl PtySession {
         rx
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_openpty_error_hints_missing_pty_device() {
+        for errno in [libc::ENOENT, libc::ENXIO] {
+            let err = openpty_error(anyhow::anyhow!("failed to openpty"), Some(errno));
+            assert!(matches!(
+                err.downcast_ref::<CoreError>(),
+                Some(CoreError::PtyUnavailable { .. })
+            ));
+            assert!(err.to_string().contains("/dev/pts"));
+        }
+    }
+
+    #[test]
+    fn test_openpty_error_keeps_other_failures_generic() {
+        #[cfg(unix)]
+        let errno = Some(libc::EMFILE);
+        #[cfg(not(unix))]
+        let errno = Some(2);
+
+        let err = openpty_error(anyhow::anyhow!("failed to openpty"), errno);
+        assert!(err.downcast_ref::<CoreError>().is_none());
+        assert!(!format!("{:#}", err).contains("/dev/pts"));
+        assert_eq!(format!("{:#}", err), "Failed to open PTY: failed to openpty");
+
+        let err = openpty_error(anyhow::anyhow!("failed to openpty"), None);
+        assert!(err.downcast_ref::<CoreError>().is_none());
+    }
+}
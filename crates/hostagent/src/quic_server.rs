@@ -4,8 +4,11 @@
 
 use anyhow::{Context, Result};
 use comacode_core::{
+    CoreError,
+    events::{EventSink, NoopEventSink, ServerEvent},
     protocol::MessageCodec,
-    transport::{configure_server, stream::pump_pty_to_quic, stream::pump_pty_to_quic_tagged},
+    terminal::TerminalConfig,
+    transport::{configure_server, stream::pump_pty_to_quic_adaptive, stream::pump_pty_to_quic_tagged, BufferConfig, RttEstimator},
     types::{NetworkMessage, SessionMessage, TerminalEvent},
 };
 use quinn::{Endpoint, TokioRuntime};
@@ -14,16 +17,40 @@ use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::{oneshot, Mutex};
-use tokio_stream::StreamExt;
+use tokio::sync::{oneshot, watch, Mutex};
 use rcgen::KeyPair;
 
 use crate::auth::TokenStore;
 use crate::ratelimit::RateLimiterStore;
-use crate::session::SessionManager;
+use crate::session::{self, SessionManager};
+use crate::shells;
 use crate::vfs;
 use crate::vfs_watcher::WatcherManager;
 
+/// How often the connection RTT is sampled for adaptive output batching
+const RTT_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Reset code for a FileChunk stream whose file failed to read
+const FILE_CHUNK_READ_FAILED: quinn::VarInt = quinn::VarInt::from_u32(1);
+
+/// Connection state shared by the handlers of one client stream
+///
+/// Built in `handle_connection` for each accepted stream.
+struct ConnectionContext {
+    /// QUIC connection (for extra streams such as chunked downloads)
+    connection: quinn::Connection,
+    /// Client address, for logs and telemetry
+    peer_addr: SocketAddr,
+    /// Telemetry sink for connection/session events
+    event_sink: Arc<dyn EventSink>,
+    /// Connection RTT estimate for adaptive output batching
+    rtt: RttEstimator,
+    /// Send side of the stream, shared with the output pumps
+    send_shared: Arc<Mutex<quinn::SendStream>>,
+    /// Output pause state from client FlowControl (app backgrounded)
+    paused_rx: watch::Receiver<bool>,
+}
+
 /// QUIC server for terminal connections
 pub struct QuicServer {
     /// QUIC endpoint
@@ -36,6 +63,8 @@ pub struct QuicServer {
     rate_limiter: Arc<RateLimiterStore>,
     /// File watcher manager for VFS (Phase VFS-3)
     watcher_mgr: Arc<WatcherManager>,
+    /// Telemetry sink for connection/session events
+    event_sink: Arc<dyn EventSink>,
     /// Shutdown signal sender
     shutdown_tx: Option<oneshot::Sender<()>>,
 }
@@ -78,6 +107,7 @@ impl QuicServer {
                 token_store,
                 rate_limiter,
                 watcher_mgr: Arc::new(WatcherManager::new()),
+                event_sink: Arc::new(NoopEventSink),
                 shutdown_tx: None,
             },
             cert,
@@ -85,6 +115,11 @@ impl QuicServer {
         ))
     }
 
+    /// Replace the event sink (defaults to `NoopEventSink`)
+    pub fn set_event_sink(&mut self, sink: Arc<dyn EventSink>) {
+        self.event_sink = sink;
+    }
+
     /// Run server (accepts connections indefinitely)
     pub async fn run(&mut self) -> Result<()> {
         let (shutdown_tx, mut shutdown_rx) = oneshot::channel();
@@ -124,8 +159,9 @@ impl QuicServer {
                             let token_store = Arc::clone(&self.token_store);
                             let rate_limiter = Arc::clone(&self.rate_limiter);
                             let watcher_mgr = Arc::clone(&self.watcher_mgr);
+                            let event_sink = Arc::clone(&self.event_sink);
                             tokio::spawn(async move {
-                                if let Err(e) = Self::handle_connection(incoming, session_mgr, token_store, rate_limiter, watcher_mgr).await {
+                                if let Err(e) = Self::handle_connection(incoming, session_mgr, token_store, rate_limiter, watcher_mgr, event_sink).await {
                                     tracing::error!("Connection error: {}", e);
                                 }
                             });
@@ -154,6 +190,7 @@ impl QuicServer {
         token_store: Arc<TokenStore>,
         rate_limiter: Arc<RateLimiterStore>,
         watcher_mgr: Arc<WatcherManager>,
+        event_sink: Arc<dyn EventSink>,
     ) -> Result<()> {
         // Accept the connection - returns Result<Connecting, ConnectionError>
         let connecting = incoming.accept()?;
@@ -161,6 +198,23 @@ impl QuicServer {
 
         let remote_addr = connection.remote_address();
         tracing::info!("Connection from {}", remote_addr);
+        event_sink.record(ServerEvent::Connected { peer_addr: remote_addr });
+
+        // Sample QUIC's RTT estimate for adaptive output batching
+        let rtt = RttEstimator::new();
+        tokio::spawn({
+            let connection = connection.clone();
+            let rtt = rtt.clone();
+            async move {
+                let mut interval = tokio::time::interval(RTT_SAMPLE_INTERVAL);
+                loop {
+                    tokio::select! {
+                        _ = interval.tick() => rtt.record_sample(connection.rtt()),
+                        _ = connection.closed() => break,
+                    }
+                }
+            }
+        });
 
         // Handle bi-directional streams
         loop {
@@ -170,8 +224,17 @@ impl QuicServer {
                     let token_store = Arc::clone(&token_store);
                     let rate_limiter = Arc::clone(&rate_limiter);
                     let watcher_mgr = Arc::clone(&watcher_mgr);
+                    let (paused_tx, paused_rx) = watch::channel(false);
+                    let ctx = ConnectionContext {
+                        connection: connection.clone(),
+                        peer_addr: remote_addr,
+                        event_sink: Arc::clone(&event_sink),
+                        rtt: rtt.clone(),
+                        send_shared: Arc::new(Mutex::new(send)),
+                        paused_rx,
+                    };
                     tokio::spawn(async move {
-                        if let Err(e) = Self::handle_stream(send, recv, session_mgr, token_store, rate_limiter, watcher_mgr, remote_addr).await {
+                        if let Err(e) = Self::handle_stream(recv, ctx, paused_tx, session_mgr, token_store, rate_limiter, watcher_mgr).await {
                             tracing::error!("Stream error: {}", e);
                         }
                     });
@@ -187,27 +250,31 @@ impl QuicServer {
             }
         }
 
+        event_sink.record(ServerEvent::Disconnected { peer_addr: remote_addr });
         Ok(())
     }
 
     /// Handle single bi-directional stream
+    ///
+    /// `paused_tx` drives `ctx.paused_rx` from client FlowControl.
     async fn handle_stream(
-        send: quinn::SendStream,
         mut recv: quinn::RecvStream,
+        ctx: ConnectionContext,
+        paused_tx: watch::Sender<bool>,
         session_mgr: Arc<SessionManager>,
         token_store: Arc<TokenStore>,
         rate_limiter: Arc<RateLimiterStore>,
         watcher_mgr: Arc<WatcherManager>,
-        peer_addr: SocketAddr,
     ) -> Result<()> {
+        let peer_addr = ctx.peer_addr;
         let mut session_id: Option<u64> = None;  // Legacy session ID
         let mut active_session_id: Option<String> = None;  // Phase 04: Active UUID session
         let mut authenticated = false;
         let mut pty_task: Option<tokio::task::JoinHandle<()>> = None;
+        // Chunked download in progress (runs beside the receive loop)
+        let mut file_read_task: Option<tokio::task::JoinHandle<()>> = None;
         let mut pending_resize: Option<(u16, u16)> = None; // Store (rows, cols) before session created
-
-        // Share send stream for PTY output forwarding
-        let send_shared = Arc::new(Mutex::new(send));
+        let mut pending_locale: Option<String> = None; // Locale from RequestPty for the legacy session
 
         // Message receive loop - read length-prefixed messages properly
         let mut recv_buffer = Vec::new(); // Buffer for incomplete reads
@@ -231,6 +298,8 @@ impl QuicServer {
                 }
             };
 
+            ctx.event_sink.record(ServerEvent::BytesReceived { peer_addr, bytes: n as u64 });
+
             // Append to recv buffer
             recv_buffer.extend_from_slice(&read_buf[..n]);
             tracing::debug!("Received {} bytes, buffer size: {}", n, recv_buffer.len());
@@ -247,42 +316,26 @@ impl QuicServer {
                     tracing::info!("Client hello protocol_version={}, app_version={}", protocol_version, app_version);
 
                     // Phase 07-A: AUTH VALIDATION (P0 fix)
-                    let token_valid = if let Some(token) = auth_token {
-                        token_store.validate(&token).await
-                    } else {
-                        tracing::warn!("No auth token provided from {}", peer_addr);
-                        false
-                    };
-
-                    if !token_valid {
-                        tracing::warn!("Auth failed for IP: {}", peer_addr);
-
-                        // Record failure for rate limiting
-                        let _ = rate_limiter.record_auth_failure(peer_addr.ip()).await;
-
+                    if !Self::authenticate(auth_token, &token_store, &rate_limiter, &ctx.event_sink, peer_addr).await {
                         // Send error response and close
-                        let mut send_lock = send_shared.lock().await;
+                        let mut send_lock = ctx.send_shared.lock().await;
                         let _ = Self::send_message(&mut *send_lock, &NetworkMessage::hello(None)).await;
                         break;
                     }
-
-                    // Reset auth failures on success
-                    rate_limiter.reset_auth_failures(peer_addr.ip()).await;
                     authenticated = true;
-                    tracing::info!("Client authenticated: {}", peer_addr);
 
                     // Validate protocol version
                     if let Err(e) = msg.validate_handshake() {
                         tracing::error!("Handshake validation failed: {}", e);
                         // Send error and close
-                        let mut send_lock = send_shared.lock().await;
+                        let mut send_lock = ctx.send_shared.lock().await;
                         let _ = Self::send_message(&mut *send_lock, &NetworkMessage::hello(None)).await;
                         break;
                     }
 
                     // Respond with Hello
                     let response = NetworkMessage::hello(None);
-                    let mut send_lock = send_shared.lock().await;
+                    let mut send_lock = ctx.send_shared.lock().await;
                     Self::send_message(&mut *send_lock, &response).await?;
                     }
                     NetworkMessage::Input { data } => {
@@ -307,11 +360,12 @@ impl QuicServer {
                     } else {
                         // Spawn new session with terminal configuration
                         let _ = Self::spawn_session_with_config(
+                            &ctx,
                             &session_mgr,
                             pending_resize,
+                            pending_locale.as_deref(),
                             &mut pty_task,
                             &mut session_id,
-                            &send_shared,
                             &data,
                         ).await;
                     }
@@ -337,11 +391,12 @@ impl QuicServer {
                     } else {
                         // Spawn new session with terminal configuration (legacy Command path)
                         let _ = Self::spawn_session_with_config(
+                            &ctx,
                             &session_mgr,
                             pending_resize,
+                            pending_locale.as_deref(),
                             &mut pty_task,
                             &mut session_id,
-                            &send_shared,
                             cmd.text.as_bytes(),
                         ).await;
                     }
@@ -349,7 +404,7 @@ impl QuicServer {
                     NetworkMessage::Ping { timestamp } => {
                     // Respond with Pong
                     let response = NetworkMessage::pong(timestamp);
-                    let mut send_lock = send_shared.lock().await;
+                    let mut send_lock = ctx.send_shared.lock().await;
                     Self::send_message(&mut *send_lock, &response).await?;
                     }
                     NetworkMessage::Resize { rows, cols } => {
@@ -368,10 +423,37 @@ impl QuicServer {
                         tracing::debug!("Stored pending resize: {}x{}", rows, cols);
                     }
                     }
+                    NetworkMessage::RequestPty { rows, cols, locale, .. } => {
+                        // Applied when the legacy session is spawned by the first Input/Command
+                        tracing::debug!("RequestPty: {}x{}, locale={:?}", rows, cols, locale);
+                        pending_resize = Some((rows, cols));
+                        pending_locale = locale;
+                    }
                     NetworkMessage::Close => {
                         tracing::info!("Received Close message");
                         break;
                     }
+                    NetworkMessage::FlowControl { paused } => {
+                        tracing::info!("FlowControl from {}: paused={}", peer_addr, paused);
+                        paused_tx.send_replace(paused);
+
+                        // Echo as ack so the client knows the pause arrived
+                        // before letting the app be suspended
+                        let mut send_lock = ctx.send_shared.lock().await;
+                        let _ = Self::send_message(&mut *send_lock, &NetworkMessage::FlowControl { paused }).await;
+                    }
+                    NetworkMessage::QueryShells => {
+                        if !authenticated {
+                            tracing::warn!("QueryShells received before authentication from {}", peer_addr);
+                            break;
+                        }
+
+                        let shells = shells::available_shells();
+                        tracing::debug!("QueryShells: {} shells available", shells.len());
+
+                        let mut send_lock = ctx.send_shared.lock().await;
+                        let _ = Self::send_message(&mut *send_lock, &NetworkMessage::ShellList { shells }).await;
+                    }
                     // ===== VFS: Directory Listing - Phase 1 =====
                     NetworkMessage::ListDir { path, depth: _ } => {
                         if !authenticated {
@@ -387,7 +469,7 @@ impl QuicServer {
                         if !path_buf.exists() {
                             let error_msg = format!("Path not found: {}", path);
                             tracing::warn!("{}", error_msg);
-                            let mut send_lock = send_shared.lock().await;
+                            let mut send_lock = ctx.send_shared.lock().await;
                             let _ = Self::send_message(&mut *send_lock, &NetworkMessage::Event(
                                 comacode_core::types::TerminalEvent::Error {
                                     message: error_msg,
@@ -430,7 +512,7 @@ impl QuicServer {
                                         entries: chunk.clone(),
                                         has_more: i < chunks.len() - 1,
                                     };
-                                    let mut send_lock = send_shared.lock().await;
+                                    let mut send_lock = ctx.send_shared.lock().await;
                                     if let Err(e) = Self::send_message(&mut *send_lock, &msg).await {
                                         tracing::error!("Failed to send DirChunk: {}", e);
                                         break;
@@ -442,7 +524,7 @@ impl QuicServer {
                             Err(e) => {
                                 let error_msg = format!("Failed to read directory: {}", e);
                                 tracing::error!("{}", error_msg);
-                                let mut send_lock = send_shared.lock().await;
+                                let mut send_lock = ctx.send_shared.lock().await;
                                 let _ = Self::send_message(&mut *send_lock, &NetworkMessage::Event(
                                     comacode_core::types::TerminalEvent::Error {
                                         message: error_msg,
@@ -466,7 +548,7 @@ impl QuicServer {
                         if !path_buf.exists() {
                             let error_msg = format!("Path not found: {}", path);
                             tracing::warn!("{}", error_msg);
-                            let mut send_lock = send_shared.lock().await;
+                            let mut send_lock = ctx.send_shared.lock().await;
                             let _ = Self::send_message(&mut *send_lock, &NetworkMessage::WatchError {
                                 watcher_id: format!("watch_{}", session_id.unwrap_or(0)),
                                 error: error_msg,
@@ -477,7 +559,7 @@ impl QuicServer {
                         if !path_buf.is_dir() {
                             let error_msg = format!("Path is not a directory: {}", path);
                             tracing::warn!("{}", error_msg);
-                            let mut send_lock = send_shared.lock().await;
+                            let mut send_lock = ctx.send_shared.lock().await;
                             let _ = Self::send_message(&mut *send_lock, &NetworkMessage::WatchError {
                                 watcher_id: format!("watch_{}", session_id.unwrap_or(0)),
                                 error: error_msg,
@@ -488,7 +570,7 @@ impl QuicServer {
                         // Start watching
                         let watcher_id = format!("watch_{}", session_id.unwrap_or(0));
                         let watcher_mgr_clone: Arc<WatcherManager> = Arc::clone(&watcher_mgr);
-                        let send_clone = send_shared.clone();
+                        let send_clone = ctx.send_shared.clone();
 
                         // Spawn watch task
                         if let Err(e) = watcher_mgr_clone.watch_directory(
@@ -511,7 +593,7 @@ impl QuicServer {
                             },
                         ).await {
                             tracing::error!("Failed to start watcher: {}", e);
-                            let mut send_lock = send_shared.lock().await;
+                            let mut send_lock = ctx.send_shared.lock().await;
                             let _ = Self::send_message(&mut *send_lock, &NetworkMessage::WatchError {
                                 watcher_id: watcher_id.clone(),
                                 error: format!("Failed to start watcher: {}", e),
@@ -520,7 +602,7 @@ impl QuicServer {
                         }
 
                         // Send WatchStarted confirmation
-                        let mut send_lock = send_shared.lock().await;
+                        let mut send_lock = ctx.send_shared.lock().await;
                         let _ = Self::send_message(&mut *send_lock, &NetworkMessage::WatchStarted {
                             watcher_id,
                         }).await;
@@ -563,7 +645,7 @@ impl QuicServer {
                                 size: 0,
                                 truncated: false,
                             };
-                            let mut send_lock = send_shared.lock().await;
+                            let mut send_lock = ctx.send_shared.lock().await;
                             let _ = Self::send_message(&mut *send_lock, &response).await;
                             continue;
                         }
@@ -590,9 +672,161 @@ impl QuicServer {
                             }
                         };
 
-                        let mut send_lock = send_shared.lock().await;
+                        let mut send_lock = ctx.send_shared.lock().await;
                         let _ = Self::send_message(&mut *send_lock, &response).await;
                     }
+                    NetworkMessage::ReadFileChunked { path, chunk_size, request_id } => {
+                        if !authenticated {
+                            tracing::warn!("ReadFileChunked received before authentication from {}", peer_addr);
+                            break;
+                        }
+
+                        tracing::info!("ReadFileChunked request {}: {} (chunk_size: {})", request_id, path, chunk_size);
+
+                        let path_buf = PathBuf::from(&path);
+
+                        // Security: Same path jail as ReadFile
+                        let current_dir = std::env::current_dir()
+                            .unwrap_or_else(|_| PathBuf::from("/"));
+
+                        let reader = match crate::vfs::validate_path(&path_buf, &current_dir) {
+                            Ok(()) => crate::vfs::FileChunkReader::open(&path_buf, chunk_size as usize, request_id).await,
+                            Err(e) => Err(e),
+                        };
+
+                        let mut reader = match reader {
+                            Ok(reader) => reader,
+                            Err(e) => {
+                                let error_msg = format!("Failed to read file: {}", e);
+                                tracing::warn!("{}", error_msg);
+                                let mut send_lock = ctx.send_shared.lock().await;
+                                let _ = Self::send_message(&mut *send_lock, &NetworkMessage::Event(
+                                    TerminalEvent::Error { message: error_msg }
+                                )).await;
+                                continue;
+                            }
+                        };
+
+                        tracing::info!("Sending {} ({} bytes) in chunks", path, reader.total_size());
+
+                        // Stream chunks on their own uni stream from a task, so a
+                        // slow client only throttles the download, never the
+                        // terminal; a new request replaces the previous download
+                        if let Some(task) = file_read_task.take() {
+                            task.abort();
+                        }
+                        let connection = ctx.connection.clone();
+                        let send_clone = ctx.send_shared.clone();
+                        file_read_task = Some(tokio::spawn(async move {
+                            let mut chunk_stream = match connection.open_uni().await {
+                                Ok(stream) => stream,
+                                Err(e) => {
+                                    tracing::error!("Failed to open FileChunk stream: {}", e);
+                                    return;
+                                }
+                            };
+                            loop {
+                                match reader.next_chunk().await {
+                                    Ok(Some(msg)) => {
+                                        // Fails when the client stops the stream (download abandoned)
+                                        if let Err(e) = Self::send_message(&mut chunk_stream, &msg).await {
+                                            tracing::debug!("FileChunk stream closed: {}", e);
+                                            break;
+                                        }
+                                    }
+                                    Ok(None) => {
+                                        let _ = chunk_stream.finish();
+                                        break;
+                                    }
+                                    Err(e) => {
+                                        let error_msg = format!("Failed to read file: {}", e);
+                                        tracing::error!("{}", error_msg);
+                                        let _ = chunk_stream.reset(FILE_CHUNK_READ_FAILED);
+                                        let mut send_lock = send_clone.lock().await;
+                                        let _ = Self::send_message(&mut *send_lock, &NetworkMessage::Event(
+                                            TerminalEvent::Error { message: error_msg }
+                                        )).await;
+                                        break;
+                                    }
+                                }
+                            }
+                        }));
+                    }
+                    // ===== VFS: File Writing =====
+                    NetworkMessage::WriteFile { path, data } => {
+                        if !authenticated {
+                            tracing::warn!("WriteFile received before authentication from {}", peer_addr);
+                            break;
+                        }
+
+                        tracing::info!("WriteFile request: {} ({} bytes)", path, data.len());
+
+                        let path_buf = PathBuf::from(&path);
+                        let current_dir = std::env::current_dir()
+                            .unwrap_or_else(|_| PathBuf::from("/"));
+
+                        let result = match vfs::validate_write_path(&path_buf, &current_dir) {
+                            Ok(()) => vfs::write_file(&path_buf, data).await,
+                            Err(e) => Err(e),
+                        };
+                        if let Err(ref e) = result {
+                            tracing::warn!("WriteFile failed: {}", e);
+                        }
+
+                        let mut send_lock = ctx.send_shared.lock().await;
+                        let _ = Self::send_message(&mut *send_lock, &vfs::write_result(path, result)).await;
+                    }
+                    NetworkMessage::WriteChunk { path, offset, data } => {
+                        if !authenticated {
+                            tracing::warn!("WriteChunk received before authentication from {}", peer_addr);
+                            break;
+                        }
+
+                        tracing::debug!("WriteChunk request: {} (offset: {}, {} bytes)", path, offset, data.len());
+
+                        let path_buf = PathBuf::from(&path);
+                        let current_dir = std::env::current_dir()
+                            .unwrap_or_else(|_| PathBuf::from("/"));
+
+                        let result = match vfs::validate_write_path(&path_buf, &current_dir) {
+                            Ok(()) => vfs::write_chunk(&path_buf, offset, data).await,
+                            Err(e) => Err(e),
+                        };
+                        if let Err(ref e) = result {
+                            tracing::warn!("WriteChunk failed: {}", e);
+                        }
+
+                        let mut send_lock = ctx.send_shared.lock().await;
+                        let _ = Self::send_message(&mut *send_lock, &vfs::write_result(path, result)).await;
+                    }
+                    // ===== Command Completion =====
+                    NetworkMessage::CompleteRequest { session_id: complete_session_id, line, cursor } => {
+                        if !authenticated {
+                            tracing::warn!("CompleteRequest received before authentication from {}", peer_addr);
+                            break;
+                        }
+
+                        tracing::debug!("CompleteRequest: session={}, cursor={}", complete_session_id, cursor);
+
+                        // Jail completion to the session's project directory;
+                        // unknown sessions get no suggestions
+                        let suggestions = match session_mgr.get_working_dir(&complete_session_id).await {
+                            Some(dir) => match vfs::complete_path(&line, cursor, &PathBuf::from(dir)).await {
+                                Ok(suggestions) => suggestions,
+                                Err(e) => {
+                                    tracing::debug!("Completion failed: {}", e);
+                                    Vec::new()
+                                }
+                            },
+                            None => {
+                                tracing::debug!("Completion for unknown session {}", complete_session_id);
+                                Vec::new()
+                            }
+                        };
+
+                        let mut send_lock = ctx.send_shared.lock().await;
+                        let _ = Self::send_message(&mut *send_lock, &NetworkMessage::complete_result(suggestions)).await;
+                    }
                     // ===== Multi-Session Support - Phase 04 =====
                     NetworkMessage::Session(session_msg) => {
                         if !authenticated {
@@ -603,29 +837,36 @@ impl QuicServer {
                         tracing::info!("Session message: {:?}", std::mem::discriminant(&session_msg));
 
                         match session_msg {
-                            SessionMessage::CreateSession { project_path, session_id } => {
-                                tracing::info!("CreateSession: project={}, session={}", project_path, session_id);
+                            SessionMessage::CreateSession { project_path, session_id, locale, shell } => {
+                                tracing::info!("CreateSession: project={}, session={}, locale={:?}, shell={:?}", project_path, session_id, locale, shell);
 
                                 // Validate project path exists
                                 let path_buf = PathBuf::from(&project_path);
                                 if !path_buf.exists() {
                                     let error_msg = format!("Project path not found: {}", project_path);
                                     tracing::warn!("{}", error_msg);
-                                    let mut send_lock = send_shared.lock().await;
+                                    let mut send_lock = ctx.send_shared.lock().await;
                                     let _ = Self::send_message(&mut *send_lock, &NetworkMessage::Event(
                                         TerminalEvent::Error { message: error_msg },
                                     )).await;
                                     break;
                                 }
 
-                                // Build terminal config
-                                let mut config = comacode_core::terminal::TerminalConfig::default();
-                                if let Some((rows, cols)) = pending_resize {
-                                    config.rows = rows;
-                                    config.cols = cols;
-                                    config.env.push(("COLUMNS".to_string(), cols.to_string()));
-                                    config.env.push(("LINES".to_string(), rows.to_string()));
+                                let mut config = session_config(pending_resize, locale.as_deref());
+                                // Security: only shells listed in /etc/shells, never arbitrary binaries
+                                if let Some(shell) = shell {
+                                    if !shells::is_allowed_shell(&shell) {
+                                        let error_msg = format!("Shell not allowed: {}", shell);
+                                        tracing::warn!("{}", error_msg);
+                                        let mut send_lock = ctx.send_shared.lock().await;
+                                        let _ = Self::send_message(&mut *send_lock, &NetworkMessage::Event(
+                                            TerminalEvent::Error { message: error_msg },
+                                        )).await;
+                                        continue;
+                                    }
+                                    config = config.with_shell(shell);
                                 }
+                                let charset = config.charset();
 
                                 // Create UUID session
                                 match session_mgr.create_session_with_uuid(
@@ -634,19 +875,24 @@ impl QuicServer {
                                     &project_path,
                                 ).await {
                                     Ok(()) => {
+                                        ctx.event_sink.record(ServerEvent::SessionCreated {
+                                            peer_addr,
+                                            session_id: session_id.clone(),
+                                        });
+
                                         // Send SessionCreated event
-                                        let mut send_lock = send_shared.lock().await;
+                                        let mut send_lock = ctx.send_shared.lock().await;
                                         let _ = Self::send_message(&mut *send_lock, &NetworkMessage::Event(
-                                            TerminalEvent::session_created(session_id.clone()),
+                                            TerminalEvent::session_created(session_id.clone(), charset),
                                         )).await;
 
                                         tracing::info!("Session {} created for project {}", session_id, project_path);
                                     }
                                     Err(e) => {
-                                        tracing::error!("Failed to create session {}: {}", session_id, e);
-                                        let mut send_lock = send_shared.lock().await;
+                                        tracing::error!("Failed to create session {}: {:#}", session_id, e);
+                                        let mut send_lock = ctx.send_shared.lock().await;
                                         let _ = Self::send_message(&mut *send_lock, &NetworkMessage::Event(
-                                            TerminalEvent::Error { message: format!("Failed to create session: {}", e) },
+                                            TerminalEvent::Error { message: session_error_message(&e) },
                                         )).await;
                                     }
                                 }
@@ -661,7 +907,7 @@ impl QuicServer {
                                     TerminalEvent::session_not_found(session_id.clone())
                                 };
 
-                                let mut send_lock = send_shared.lock().await;
+                                let mut send_lock = ctx.send_shared.lock().await;
                                 let _ = Self::send_message(&mut *send_lock, &NetworkMessage::Event(event)).await;
                             }
                             SessionMessage::SwitchSession { session_id } => {
@@ -669,7 +915,7 @@ impl QuicServer {
 
                                 // Check if session exists
                                 if !session_mgr.session_exists(&session_id).await {
-                                    let mut send_lock = send_shared.lock().await;
+                                    let mut send_lock = ctx.send_shared.lock().await;
                                     let _ = Self::send_message(&mut *send_lock, &NetworkMessage::Event(
                                         TerminalEvent::session_not_found(session_id.clone()),
                                     )).await;
@@ -687,7 +933,7 @@ impl QuicServer {
 
                                 // Send history if available
                                 if !history.is_empty() {
-                                    let mut send_lock = send_shared.lock().await;
+                                    let mut send_lock = ctx.send_shared.lock().await;
                                     let _ = Self::send_message(&mut *send_lock, &NetworkMessage::SessionHistory {
                                         session_id: session_id.clone(),
                                         lines: history,
@@ -701,20 +947,25 @@ impl QuicServer {
                                 if let Some(output_rx) = session_mgr.take_output_rx_for_session(&session_id).await {
                                     let history_tx = session_mgr.get_history_sender(&session_id).await;
                                     let session_key = session_id.clone();
-                                    let send_clone = send_shared.clone();
+                                    let send_clone = ctx.send_shared.clone();
+                                    let pump_sink = Arc::clone(&ctx.event_sink);
+                                    let rtt = ctx.rtt.clone();
+                                    // Convert Receiver to AsyncRead (held while client is paused)
+                                    let pty_reader = tokio_util::io::InspectReader::new(
+                                        session::gated_reader(output_rx, ctx.paused_rx.clone()),
+                                        move |buf: &[u8]| {
+                                            pump_sink.record(ServerEvent::BytesSent { peer_addr, bytes: buf.len() as u64 });
+                                        },
+                                    );
 
                                     let pump_handle = tokio::spawn(async move {
-                                        let mut send_lock = send_clone.lock().await;
                                         if let Err(e) = pump_pty_to_quic_tagged(
-                                            // Convert Receiver to AsyncRead
-                                            {
-                                                let stream = tokio_stream::wrappers::ReceiverStream::new(output_rx)
-                                                    .map(Ok::<_, std::io::Error>);
-                                                tokio_util::io::StreamReader::new(stream)
-                                            },
-                                            &mut *send_lock,
+                                            pty_reader,
+                                            &send_clone,
                                             session_key.clone(),
                                             history_tx,
+                                            BufferConfig::adaptive(),
+                                            &rtt,
                                         ).await {
                                             tracing::error!("TaggedOutput pump error for session {}: {}", session_key, e);
                                         }
@@ -729,7 +980,7 @@ impl QuicServer {
                                 }
 
                                 // Send SessionSwitched event
-                                let mut send_lock = send_shared.lock().await;
+                                let mut send_lock = ctx.send_shared.lock().await;
                                 let _ = Self::send_message(&mut *send_lock, &NetworkMessage::Event(
                                     TerminalEvent::session_switched(session_id.clone()),
                                 )).await;
@@ -741,8 +992,13 @@ impl QuicServer {
 
                                 match session_mgr.close_session(&session_id).await {
                                     Ok(()) => {
+                                        ctx.event_sink.record(ServerEvent::SessionClosed {
+                                            peer_addr,
+                                            session_id: session_id.clone(),
+                                        });
+
                                         // Send SessionClosed event
-                                        let mut send_lock = send_shared.lock().await;
+                                        let mut send_lock = ctx.send_shared.lock().await;
                                         let _ = Self::send_message(&mut *send_lock, &NetworkMessage::Event(
                                             TerminalEvent::session_closed(session_id.clone()),
                                         )).await;
@@ -756,7 +1012,7 @@ impl QuicServer {
                                     }
                                     Err(e) => {
                                         tracing::error!("Failed to close session {}: {}", session_id, e);
-                                        let mut send_lock = send_shared.lock().await;
+                                        let mut send_lock = ctx.send_shared.lock().await;
                                         let _ = Self::send_message(&mut *send_lock, &NetworkMessage::Event(
                                             TerminalEvent::Error { message: format!("Failed to close session: {}", e) },
                                         )).await;
@@ -769,7 +1025,7 @@ impl QuicServer {
                                 let sessions = session_mgr.list_uuid_sessions().await;
                                 let response_text = format!("Active sessions:\n{}", sessions.join("\n"));
 
-                                let mut send_lock = send_shared.lock().await;
+                                let mut send_lock = ctx.send_shared.lock().await;
                                 let _ = Self::send_message(&mut *send_lock, &NetworkMessage::Event(
                                     TerminalEvent::Output { data: response_text.into_bytes() },
                                 )).await;
@@ -785,7 +1041,14 @@ impl QuicServer {
 
         // Cleanup session on disconnect
         if let Some(id) = session_id {
-            let _ = session_mgr.cleanup_session(id).await;
+            if session_mgr.cleanup_session(id).await.is_ok() {
+                ctx.event_sink.record(ServerEvent::SessionClosed { peer_addr, session_id: id.to_string() });
+            }
+        }
+
+        // Stop any download still streaming to the closed stream
+        if let Some(task) = file_read_task {
+            task.abort();
         }
 
         // Wait for PTY pump task to complete
@@ -796,19 +1059,54 @@ impl QuicServer {
         Ok(())
     }
 
+    /// Validate Hello auth token and update rate limiter
+    ///
+    /// Returns true if the client is authenticated.
+    async fn authenticate(
+        auth_token: Option<comacode_core::AuthToken>,
+        token_store: &TokenStore,
+        rate_limiter: &RateLimiterStore,
+        event_sink: &Arc<dyn EventSink>,
+        peer_addr: SocketAddr,
+    ) -> bool {
+        let token_valid = if let Some(token) = auth_token {
+            token_store.validate(&token).await
+        } else {
+            tracing::warn!("No auth token provided from {}", peer_addr);
+            false
+        };
+
+        if !token_valid {
+            tracing::warn!("Auth failed for IP: {}", peer_addr);
+            event_sink.record(ServerEvent::AuthFailed { peer_addr });
+
+            // Record failure for rate limiting
+            let _ = rate_limiter.record_auth_failure(peer_addr.ip()).await;
+            return false;
+        }
+
+        // Reset auth failures on success
+        rate_limiter.reset_auth_failures(peer_addr.ip()).await;
+        tracing::info!("Client authenticated: {}", peer_addr);
+        event_sink.record(ServerEvent::AuthSucceeded { peer_addr });
+        true
+    }
+
     /// Spawn session with terminal configuration
     ///
     /// Shared helper for Input and Command message handlers.
     /// Creates PTY session, applies resize, spawns output pump task.
     async fn spawn_session_with_config(
+        ctx: &ConnectionContext,
         session_mgr: &Arc<SessionManager>,
         pending_resize: Option<(u16, u16)>,
+        locale: Option<&str>,
         pty_task: &mut Option<tokio::task::JoinHandle<()>>,
         session_id: &mut Option<u64>,
-        send_shared: &Arc<Mutex<quinn::SendStream>>,
         initial_data: &[u8],
     ) -> Result<()> {
-        let mut config = comacode_core::terminal::TerminalConfig::default();
+        let peer_addr = ctx.peer_addr;
+        let mut config = TerminalConfig::default();
 
         // Apply terminal size from earlier Resize message
         if let Some((rows, cols)) = pending_resize {
@@ -820,11 +1118,15 @@ impl QuicServer {
             // Hide % marker if Zsh thinks line is incomplete
             config.env.push(("PROMPT_EOL_MARK".to_string(), "".to_string()));
         }
+        if let Some(locale) = locale {
+            config = config.with_locale(locale);
+        }
 
         match session_mgr.create_session(config).await {
             Ok(id) => {
                 *session_id = Some(id);
                 tracing::info!("Created session {} for connection", id);
+                ctx.event_sink.record(ServerEvent::SessionCreated { peer_addr, session_id: id.to_string() });
 
                 // Resize PTY to match terminal size
                 // This syncs the PTY driver with env vars
@@ -834,11 +1136,15 @@ impl QuicServer {
                 }
 
                 // Spawn PTY->QUIC pump task
-                if let Some(pty_reader) = session_mgr.get_pty_reader(id).await {
-                    let send_clone = send_shared.clone();
+                if let Some(pty_reader) = session_mgr.get_pty_reader(id, ctx.paused_rx.clone()).await {
+                    let pump_sink = Arc::clone(&ctx.event_sink);
+                    let pty_reader = tokio_util::io::InspectReader::new(pty_reader, move |buf: &[u8]| {
+                        pump_sink.record(ServerEvent::BytesSent { peer_addr, bytes: buf.len() as u64 });
+                    });
+                    let send_clone = ctx.send_shared.clone();
+                    let rtt = ctx.rtt.clone();
                     *pty_task = Some(tokio::spawn(async move {
-                        let mut send_lock = send_clone.lock().await;
-                        if let Err(e) = pump_pty_to_quic(pty_reader, &mut *send_lock).await {
+                        if let Err(e) = pump_pty_to_quic_adaptive(pty_reader, &send_clone, BufferConfig::adaptive(), &rtt).await {
                             tracing::error!("PTY->QUIC pump error: {}", e);
                         }
                         tracing::debug!("PTY->QUIC pump completed");
@@ -856,7 +1162,11 @@ impl QuicServer {
                 Ok(())
             }
             Err(e) => {
-                tracing::error!("Failed to create session: {}", e);
+                tracing::error!("Failed to create session: {:#}", e);
+                let mut send_lock = ctx.send_shared.lock().await;
+                let _ = Self::send_message(&mut *send_lock, &NetworkMessage::Event(
+                    TerminalEvent::Error { message: session_error_message(&e) },
+                )).await;
                 Err(e)
             }
         }
@@ -924,6 +1234,34 @@ impl QuicServer {
     }
 }
 
+/// Terminal config for a CreateSession request
+///
+/// Applies the size from an earlier Resize and the client's locale.
+fn session_config(pending_resize: Option<(u16, u16)>, locale: Option<&str>) -> TerminalConfig {
+    let mut config = TerminalConfig::default();
+    if let Some((rows, cols)) = pending_resize {
+        config.rows = rows;
+        config.cols = cols;
+        config.env.push(("COLUMNS".to_string(), cols.to_string()));
+        config.env.push(("LINES".to_string(), rows.to_string()));
+    }
+    if let Some(locale) = locale {
+        config = config.with_locale(locale);
+    }
+    config
+}
+
+/// Client-facing message for a session creation failure
+///
+/// PTY allocation failures get the actionable `PtyUnavailable` text instead
+/// of the generic context message.
+fn session_error_message(err: &anyhow::Error) -> String {
+    match err.downcast_ref::<CoreError>() {
+        Some(pty_err @ CoreError::PtyUnavailable { .. }) => pty_err.to_string(),
+        _ => format!("Failed to create session: {}", err),
+    }
+}
+
 /// Generate self-signed TLS certificate with keypair
 fn generate_cert_with_keypair() -> Result<(CertificateDer<'static>, KeyPair)> {
     use rcgen;
@@ -937,3 +1275,213 @@ fn generate_cert_with_keypair() -> Result<(CertificateDer<'static>, KeyPair)> {
         cert.key_pair,
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex as StdMutex;
+
+    /// Sink that keeps every event for assertions
+    #[derive(Default)]
+    struct RecordingSink {
+        events: StdMutex<Vec<ServerEvent>>,
+    }
+
+    impl EventSink for RecordingSink {
+        fn record(&self, event: ServerEvent) {
+            self.events.lock().unwrap().push(event);
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_session_error_message_pty_unavailable() {
+        let err = crate::pty::openpty_error(anyhow::anyhow!("failed to openpty"), Some(libc::ENOENT))
+            .context("Failed to create PTY session abc");
+
+        let msg = session_error_message(&err);
+        assert!(msg.starts_with("PTY unavailable"));
+        assert!(msg.contains("/dev/pts"));
+
+        let other = anyhow::anyhow!("spawn failed").context("Failed to create PTY session abc");
+        assert_eq!(session_error_message(&other), "Failed to create session: Failed to create PTY session abc");
+    }
+
+    #[test]
+    fn test_session_config_applies_locale() {
+        let config = session_config(Some((30, 100)), Some("ja_JP.EUC-JP"));
+        assert!(config.env.contains(&("LANG".to_string(), "ja_JP.EUC-JP".to_string())));
+        assert!(config.env.contains(&("LC_ALL".to_string(), "ja_JP.EUC-JP".to_string())));
+        assert!(config.env.contains(&("COLUMNS".to_string(), "100".to_string())));
+        assert_eq!(config.charset(), "EUC-JP");
+
+        // No locale keeps the host default
+        assert_eq!(session_config(None, None).env, TerminalConfig::default().env);
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_records_events() {
+        let token_store = TokenStore::new();
+        let rate_limiter = RateLimiterStore::new();
+        let recorder = Arc::new(RecordingSink::default());
+        let sink: Arc<dyn EventSink> = recorder.clone();
+        let peer_addr: SocketAddr = "127.0.0.1:5000".parse().unwrap();
+
+        let token = token_store.generate_token().await;
+        assert!(QuicServer::authenticate(Some(token), &token_store, &rate_limiter, &sink, peer_addr).await);
+        assert!(!QuicServer::authenticate(None, &token_store, &rate_limiter, &sink, peer_addr).await);
+        assert!(!QuicServer::authenticate(
+            Some(comacode_core::AuthToken::generate()),
+            &token_store,
+            &rate_limiter,
+            &sink,
+            peer_addr,
+        ).await);
+
+        let events = recorder.events.lock().unwrap();
+        assert_eq!(*events, vec![
+            ServerEvent::AuthSucceeded { peer_addr },
+            ServerEvent::AuthFailed { peer_addr },
+            ServerEvent::AuthFailed { peer_addr },
+        ]);
+    }
+
+    /// Read one length-prefixed message from the server
+    async fn recv_message(recv: &mut quinn::RecvStream) -> NetworkMessage {
+        let mut len_buf = [0u8; 4];
+        recv.read_exact(&mut len_buf).await.unwrap();
+        let mut frame = len_buf.to_vec();
+        frame.resize(4 + u32::from_be_bytes(len_buf) as usize, 0);
+        recv.read_exact(&mut frame[4..]).await.unwrap();
+        MessageCodec::decode(&frame).unwrap()
+    }
+
+    /// Send one message to the server, adding its wire size to `written`
+    async fn send_counted(send: &mut quinn::SendStream, msg: &NetworkMessage, written: &mut u64) {
+        let encoded = MessageCodec::encode(msg).unwrap();
+        *written += encoded.len() as u64;
+        send.write_all(&encoded).await.unwrap();
+    }
+
+    /// Wait until the sink has recorded `event`, failing after 10s
+    async fn wait_for_event(recorder: &RecordingSink, event: ServerEvent) {
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while !recorder.events.lock().unwrap().contains(&event) {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        })
+        .await
+        .unwrap_or_else(|_| panic!("event not recorded: {:?}", event));
+    }
+
+    /// Read messages until one matches, failing after 10s
+    async fn recv_until(
+        recv: &mut quinn::RecvStream,
+        pred: impl Fn(&NetworkMessage) -> bool,
+    ) -> NetworkMessage {
+        tokio::time::timeout(Duration::from_secs(10), async {
+            loop {
+                let msg = recv_message(recv).await;
+                if pred(&msg) {
+                    return msg;
+                }
+            }
+        })
+        .await
+        .expect("timed out waiting for message")
+    }
+
+    #[tokio::test]
+    async fn test_connection_lifecycle_records_events() {
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let token_store = Arc::new(TokenStore::new());
+        let token = token_store.generate_token().await;
+        let (mut server, cert, _key) = QuicServer::new(
+            "127.0.0.1:0".parse().unwrap(),
+            Arc::clone(&token_store),
+            Arc::new(RateLimiterStore::new()),
+        ).await.unwrap();
+        let recorder = Arc::new(RecordingSink::default());
+        server.set_event_sink(recorder.clone());
+        let server_addr = server.endpoint.local_addr().unwrap();
+        tokio::spawn(async move { server.run().await });
+
+        // Client trusting the server's self-signed certificate
+        let mut roots = rustls::RootCertStore::empty();
+        roots.add(cert).unwrap();
+        let crypto = rustls::ClientConfig::builder()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        let quic_crypto = quinn::crypto::rustls::QuicClientConfig::try_from(crypto).unwrap();
+        let mut client = Endpoint::client("127.0.0.1:0".parse().unwrap()).unwrap();
+        client.set_default_client_config(comacode_core::transport::configure_client(Arc::new(quic_crypto)));
+        let peer_addr = client.local_addr().unwrap();
+
+        let connection = client.connect(server_addr, "Comacode").unwrap().await.unwrap();
+        let (mut send, mut recv) = connection.open_bi().await.unwrap();
+        let mut written = 0u64;
+
+        send_counted(&mut send, &NetworkMessage::hello(Some(token)), &mut written).await;
+        recv_until(&mut recv, |m| matches!(m, NetworkMessage::Hello { .. })).await;
+
+        // First Input spawns the stream's session; echo guarantees some output
+        send_counted(&mut send, &NetworkMessage::Input { data: b"echo hi\n".to_vec() }, &mut written).await;
+        recv_until(&mut recv, |m| matches!(m, NetworkMessage::Event(TerminalEvent::Output { .. }))).await;
+
+        // Ending the stream closes its session, then the connection goes
+        send.finish().unwrap();
+        let session_id = tokio::time::timeout(Duration::from_secs(10), async {
+            loop {
+                let closed = recorder.events.lock().unwrap().iter().find_map(|e| match e {
+                    ServerEvent::SessionClosed { session_id, .. } => Some(session_id.clone()),
+                    _ => None,
+                });
+                match closed {
+                    Some(id) => break id,
+                    None => tokio::time::sleep(Duration::from_millis(20)).await,
+                }
+            }
+        })
+        .await
+        .expect("session not closed");
+
+        connection.close(0u32.into(), b"done");
+        wait_for_event(&recorder, ServerEvent::Disconnected { peer_addr }).await;
+
+        let events = recorder.events.lock().unwrap();
+
+        // Lifecycle events in order, all attributed to this client
+        let lifecycle: Vec<_> = events
+            .iter()
+            .filter(|e| !matches!(e, ServerEvent::BytesReceived { .. } | ServerEvent::BytesSent { .. }))
+            .cloned()
+            .collect();
+        assert_eq!(lifecycle, vec![
+            ServerEvent::Connected { peer_addr },
+            ServerEvent::AuthSucceeded { peer_addr },
+            ServerEvent::SessionCreated { peer_addr, session_id: session_id.clone() },
+            ServerEvent::SessionClosed { peer_addr, session_id },
+            ServerEvent::Disconnected { peer_addr },
+        ]);
+
+        // Byte counters: every byte the client wrote is counted once
+        let received: u64 = events
+            .iter()
+            .filter_map(|e| match e {
+                ServerEvent::BytesReceived { peer_addr: addr, bytes } if *addr == peer_addr => Some(*bytes),
+                _ => None,
+            })
+            .sum();
+        assert_eq!(received, written);
+
+        let sent: u64 = events
+            .iter()
+            .filter_map(|e| match e {
+                ServerEvent::BytesSent { peer_addr: addr, bytes } if *addr == peer_addr => Some(*bytes),
+                _ => None,
+            })
+            .sum();
+        assert!(sent > 0, "terminal output should be counted");
+    }
+}
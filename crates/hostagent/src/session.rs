@@ -11,9 +11,8 @@ use comacode_core::terminal::TerminalConfig;
 use std::collections::{HashMap, VecDeque};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::io::AsyncReadExt;
-use tokio::sync::{mpsc, Mutex};
-use tokio_stream::StreamExt;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::sync::{mpsc, watch, Mutex};
 use tokio_stream::wrappers::ReceiverStream;
 use tokio_util::io::StreamReader;
 
@@ -104,6 +103,25 @@ impl SessionData {
     }
 }
 
+/// Convert PTY output receiver into AsyncRead that holds output while paused
+///
+/// Chunks are held rather than dropped, so a paused client applies backpressure
+/// to the PTY and nothing is lost. If the pause sender is dropped (connection
+/// gone), output is released.
+pub fn gated_reader(
+    rx: mpsc::Receiver<Bytes>,
+    paused: watch::Receiver<bool>,
+) -> impl AsyncRead + Unpin + Send {
+    let stream = futures::StreamExt::then(ReceiverStream::new(rx), move |chunk| {
+        let mut paused = paused.clone();
+        async move {
+            let _ = paused.wait_for(|p| !*p).await;
+            Ok::<_, std::io::Error>(chunk)
+        }
+    });
+    StreamReader::new(Box::pin(stream))
+}
+
 /// Session manager for PTY instances
 pub struct SessionManager {
     /// Active sessions (legacy u64 ID -> PTY)
@@ -218,12 +236,17 @@ impl SessionManager {
     }
 
     /// Get PTY output as AsyncRead for QUIC forwarding (legacy)
-    pub async fn get_pty_reader(&self, session_id: u64) -> Option<impl AsyncReadExt + Unpin + Send> {
+    ///
+    /// Output is held while `paused` is true (see `gated_reader`).
+    pub async fn get_pty_reader(
+        &self,
+        session_id: u64,
+        paused: watch::Receiver<bool>,
+    ) -> Option<impl AsyncReadExt + Unpin + Send> {
         let mut outputs = self.outputs_legacy.lock().await;
         let rx = outputs.remove(&session_id)?;
 
-        let stream = ReceiverStream::new(rx).map(Ok::<_, std::io::Error>);
-        Some(StreamReader::new(stream))
+        Some(gated_reader(rx, paused))
     }
 
     // ===== UUID-based API (Phase 04: Multi-Session Support) =====
@@ -311,6 +334,12 @@ impl SessionManager {
             .unwrap_or_default()
     }
 
+    /// Get working directory (project path) for session
+    pub async fn get_working_dir(&self, session_id: &str) -> Option<String> {
+        let sessions = self.sessions_uuid.lock().await;
+        sessions.get(session_id).map(|s| s.working_dir.clone())
+    }
+
     /// Add line to history (max 100 lines)
     #[allow(dead_code)]  // Phase 04: Used for history tracking
     pub async fn add_to_history(&self, session_id: &str, line: String) {
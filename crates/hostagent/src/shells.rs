@@ -0,0 +1,103 @@
+//! Available login shells on the host
+//!
+//! Lists shells from `/etc/shells` so the mobile app can offer a picker when
+//! creating a session. Also used to validate the shell requested by a client.
+
+#[cfg(unix)]
+use std::path::Path;
+
+/// Path to the system shell list
+#[cfg(unix)]
+const SHELLS_FILE: &str = "/etc/shells";
+
+/// Fallback when `/etc/shells` is missing or lists nothing usable
+#[cfg(unix)]
+const DEFAULT_SHELLS: &[&str] = &["/bin/sh", "/bin/bash", "/bin/zsh", "/usr/bin/fish"];
+
+#[cfg(windows)]
+const DEFAULT_SHELLS: &[&str] = &["cmd.exe", "powershell.exe"];
+
+/// Parse `/etc/shells` content
+///
+/// Skips blank lines and `#` comments; keeps order and drops duplicates.
+pub fn parse_shells(content: &str) -> Vec<String> {
+    let mut shells: Vec<String> = Vec::new();
+    for line in content.lines() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() || shells.iter().any(|s| s == line) {
+            continue;
+        }
+        shells.push(line.to_string());
+    }
+    shells
+}
+
+/// List shells installed on the host
+///
+/// Only shells whose executable exists are returned.
+#[cfg(unix)]
+pub fn available_shells() -> Vec<String> {
+    let listed = std::fs::read_to_string(SHELLS_FILE)
+        .map(|content| parse_shells(&content))
+        .unwrap_or_default();
+
+    let shells: Vec<String> = listed.into_iter().filter(|s| is_executable(Path::new(s))).collect();
+    if !shells.is_empty() {
+        return shells;
+    }
+
+    tracing::debug!("No usable shells in {}, using defaults", SHELLS_FILE);
+    DEFAULT_SHELLS
+        .iter()
+        .filter(|s| is_executable(Path::new(s)))
+        .map(|s| s.to_string())
+        .collect()
+}
+
+#[cfg(windows)]
+pub fn available_shells() -> Vec<String> {
+    DEFAULT_SHELLS.iter().map(|s| s.to_string()).collect()
+}
+
+/// Check if a shell requested by a client is one we list
+pub fn is_allowed_shell(shell: &str) -> bool {
+    available_shells().iter().any(|s| s == shell)
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    std::fs::metadata(path)
+        .map(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_shells() {
+        let content = "\
+# /etc/shells: valid login shells
+/bin/sh
+/bin/bash
+/usr/bin/bash
+
+  /bin/zsh
+/usr/bin/fish # installed via package
+/bin/bash
+";
+        assert_eq!(
+            parse_shells(content),
+            vec!["/bin/sh", "/bin/bash", "/usr/bin/bash", "/bin/zsh", "/usr/bin/fish"]
+        );
+    }
+
+    #[test]
+    fn test_parse_shells_empty() {
+        assert!(parse_shells("").is_empty());
+        assert!(parse_shells("# only comments\n\n").is_empty());
+    }
+}
@@ -1,10 +1,14 @@
 //! Virtual File System operations
 //!
-//! Provides directory reading, file listing, and path validation for VFS browsing.
+//! Provides directory reading, file listing, file writing, and path validation for VFS browsing.
 
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Seek, SeekFrom, Write};
 use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use tokio::fs;
-use comacode_core::{types::DirEntry, CoreError};
+use tokio::io::AsyncReadExt;
+use comacode_core::{types::DirEntry, CoreError, NetworkMessage, WriteError};
 
 /// VFS operation result
 pub type VfsResult<T> = Result<T, VfsError>;
@@ -16,6 +20,8 @@ pub enum VfsError {
     PathNotFound(String),
     NotADirectory(String),
     PermissionDenied(String),
+    DiskFull { path: String, written: u64 },
+    WritePartial { path: String, written: u64, expected: u64 },
 }
 
 impl std::fmt::Display for VfsError {
@@ -25,6 +31,12 @@ impl std::fmt::Display for VfsError {
             VfsError::PathNotFound(p) => write!(f, "Path not found: {}", p),
             VfsError::NotADirectory(p) => write!(f, "Not a directory: {}", p),
             VfsError::PermissionDenied(p) => write!(f, "Permission denied: {}", p),
+            VfsError::DiskFull { path, written } => {
+                write!(f, "Disk full: {} ({} bytes written)", path, written)
+            }
+            VfsError::WritePartial { path, written, expected } => {
+                write!(f, "Partial write: {} ({} of {} bytes written)", path, written, expected)
+            }
         }
     }
 }
@@ -38,6 +50,9 @@ impl From<VfsError> for CoreError {
             VfsError::NotADirectory(p) => CoreError::NotADirectory(p),
             VfsError::PermissionDenied(p) => CoreError::PermissionDenied(p),
             VfsError::IoError(e) => CoreError::VfsIoError(e),
+            other @ (VfsError::DiskFull { .. } | VfsError::WritePartial { .. }) => {
+                CoreError::VfsIoError(other.to_string())
+            }
         }
     }
 }
@@ -159,6 +174,321 @@ pub async fn read_file(path: &Path, max_size: usize) -> VfsResult<String> {
     Ok(String::from_utf8_lossy(&content).to_string())
 }
 
+/// Chunk size bounds for chunked file reads
+const MIN_FILE_CHUNK_SIZE: usize = 4 * 1024;
+const MAX_FILE_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Sequential chunk reader for large files
+///
+/// File size is captured once at open and reported in the first chunk, so
+/// the client can show progress. Reads stop at that size even if the file
+/// grows meanwhile.
+pub struct FileChunkReader {
+    file: fs::File,
+    path: String,
+    total_size: u64,
+    offset: u64,
+    chunk_index: u32,
+    chunk_size: usize,
+    request_id: u32,
+}
+
+impl FileChunkReader {
+    /// Open file for chunked reading
+    ///
+    /// `chunk_size` is clamped to 4KB..1MB. `request_id` is copied into
+    /// every chunk.
+    pub async fn open(path: &Path, chunk_size: usize, request_id: u32) -> VfsResult<Self> {
+        if !path.exists() {
+            return Err(VfsError::PathNotFound(path.display().to_string()));
+        }
+
+        if path.is_dir() {
+            return Err(VfsError::IoError(format!("Path is a directory: {}", path.display())));
+        }
+
+        let file = fs::File::open(path)
+            .await
+            .map_err(|e| {
+                if e.kind() == std::io::ErrorKind::PermissionDenied {
+                    VfsError::PermissionDenied(path.display().to_string())
+                } else {
+                    VfsError::IoError(e.to_string())
+                }
+            })?;
+
+        // Stat the open handle (not the path) so size matches what we read
+        let total_size = file.metadata()
+            .await
+            .map_err(|e| VfsError::IoError(e.to_string()))?
+            .len();
+
+        Ok(Self {
+            file,
+            path: path.display().to_string(),
+            total_size,
+            offset: 0,
+            chunk_index: 0,
+            chunk_size: chunk_size.clamp(MIN_FILE_CHUNK_SIZE, MAX_FILE_CHUNK_SIZE),
+            request_id,
+        })
+    }
+
+    /// File size captured at open
+    pub fn total_size(&self) -> u64 {
+        self.total_size
+    }
+
+    /// Read next FileChunk message
+    ///
+    /// Always yields at least one chunk (empty for empty files), then None.
+    pub async fn next_chunk(&mut self) -> VfsResult<Option<NetworkMessage>> {
+        if self.chunk_index > 0 && self.offset >= self.total_size {
+            return Ok(None);
+        }
+
+        let remaining = self.total_size - self.offset;
+        let len = remaining.min(self.chunk_size as u64) as usize;
+        let mut data = vec![0u8; len];
+        self.file.read_exact(&mut data)
+            .await
+            .map_err(|e| VfsError::IoError(e.to_string()))?;
+
+        let msg = NetworkMessage::FileChunk {
+            path: self.path.clone(),
+            chunk_index: self.chunk_index,
+            offset: self.offset,
+            data,
+            total_size: (self.chunk_index == 0).then_some(self.total_size),
+            has_more: self.offset + (len as u64) < self.total_size,
+            request_id: self.request_id,
+        };
+
+        self.offset += len as u64;
+        self.chunk_index += 1;
+        Ok(Some(msg))
+    }
+}
+
+/// Write whole file atomically
+///
+/// Writes to a uniquely named temp file next to the target, syncs it, then
+/// renames over the target, so the target is untouched on failure. Refuses
+/// targets that are symlinks or not regular files. Returns bytes written.
+/// On error, `DiskFull`/`WritePartial` carry how many bytes landed before it.
+pub async fn write_file(path: &Path, data: Vec<u8>) -> VfsResult<u64> {
+    let path = path.to_path_buf();
+    tokio::task::spawn_blocking(move || write_file_blocking(&path, &data))
+        .await
+        .map_err(|e| VfsError::IoError(e.to_string()))?
+}
+
+/// Write data at offset (for chunked uploads)
+///
+/// Creates the file if missing and syncs after writing. Refuses targets that
+/// are symlinks or not regular files. Not atomic: on error, the first
+/// `written` bytes of the chunk may already be on disk.
+pub async fn write_chunk(path: &Path, offset: u64, data: Vec<u8>) -> VfsResult<u64> {
+    let path = path.to_path_buf();
+    tokio::task::spawn_blocking(move || write_chunk_blocking(&path, offset, &data))
+        .await
+        .map_err(|e| VfsError::IoError(e.to_string()))?
+}
+
+fn write_file_blocking(path: &Path, data: &[u8]) -> VfsResult<u64> {
+    static TMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let file_name = path.file_name()
+        .ok_or_else(|| VfsError::IoError(format!("Invalid file path: {}", path.display())))?;
+    let expected = data.len() as u64;
+    let existing = check_write_target(path)?;
+
+    // Unique per write, and create_new never follows a planted symlink
+    let tmp_path = path.with_file_name(format!(
+        ".{}.{}.{}.comacode-tmp",
+        file_name.to_string_lossy(),
+        std::process::id(),
+        TMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&tmp_path)
+        .map_err(|e| write_error(e, path, 0, expected))?;
+
+    // Keep permissions of the file being replaced
+    if let Some(metadata) = existing {
+        let _ = file.set_permissions(metadata.permissions());
+    }
+
+    let (written, err) = write_all_tracked(&mut file, data);
+    let result = match err {
+        Some(e) => Err(write_error(e, path, written, expected)),
+        None => file.sync_all().map_err(|e| write_error(e, path, written, expected)),
+    };
+    drop(file);
+
+    let result = result.and_then(|()| {
+        std::fs::rename(&tmp_path, path).map_err(|e| write_error(e, path, written, expected))
+    });
+    if let Err(e) = result {
+        let _ = std::fs::remove_file(&tmp_path);
+        return Err(e);
+    }
+
+    Ok(written)
+}
+
+fn write_chunk_blocking(path: &Path, offset: u64, data: &[u8]) -> VfsResult<u64> {
+    let expected = data.len() as u64;
+    check_write_target(path)?;
+
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(false);
+    // Symlink swapped in after the check fails to open (ELOOP)
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.custom_flags(libc::O_NOFOLLOW);
+    }
+    let mut file = options.open(path)
+        .map_err(|e| write_error(e, path, 0, expected))?;
+
+    let is_file = file.metadata()
+        .map_err(|e| write_error(e, path, 0, expected))?
+        .is_file();
+    if !is_file {
+        return Err(VfsError::PermissionDenied(format!("Not a regular file: {}", path.display())));
+    }
+
+    file.seek(SeekFrom::Start(offset))
+        .map_err(|e| write_error(e, path, 0, expected))?;
+
+    let (written, err) = write_all_tracked(&mut file, data);
+    if let Some(e) = err {
+        return Err(write_error(e, path, written, expected));
+    }
+
+    file.sync_data()
+        .map_err(|e| write_error(e, path, written, expected))?;
+
+    Ok(written)
+}
+
+/// Refuse write targets that are symlinks or not regular files
+///
+/// A symlink would redirect the write past the parent directory check, and
+/// devices or FIFOs are never upload targets. Returns the metadata of an
+/// existing target; None if it does not exist yet.
+fn check_write_target(path: &Path) -> VfsResult<Option<std::fs::Metadata>> {
+    match std::fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_symlink() => Err(VfsError::PermissionDenied(
+            format!("Refusing to write through symlink: {}", path.display()),
+        )),
+        Ok(metadata) if !metadata.is_file() => Err(VfsError::PermissionDenied(
+            format!("Not a regular file: {}", path.display()),
+        )),
+        Ok(metadata) => Ok(Some(metadata)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(VfsError::IoError(e.to_string())),
+    }
+}
+
+/// Same loop as `write_all`, but also returns how many bytes were written
+fn write_all_tracked(writer: &mut impl Write, data: &[u8]) -> (u64, Option<std::io::Error>) {
+    let mut written = 0;
+    while written < data.len() {
+        match writer.write(&data[written..]) {
+            Ok(0) => return (written as u64, Some(ErrorKind::WriteZero.into())),
+            Ok(n) => written += n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return (written as u64, Some(e)),
+        }
+    }
+    (written as u64, None)
+}
+
+/// Classify a write failure so the client can tell disk-full from short writes
+fn write_error(err: std::io::Error, path: &Path, written: u64, expected: u64) -> VfsError {
+    let path = path.display().to_string();
+    match err.kind() {
+        ErrorKind::StorageFull | ErrorKind::QuotaExceeded => VfsError::DiskFull { path, written },
+        ErrorKind::PermissionDenied | ErrorKind::ReadOnlyFilesystem => VfsError::PermissionDenied(path),
+        ErrorKind::NotFound => VfsError::PathNotFound(path),
+        _ if written < expected && (written > 0 || err.kind() == ErrorKind::WriteZero) => {
+            VfsError::WritePartial { path, written, expected }
+        }
+        _ => VfsError::IoError(err.to_string()),
+    }
+}
+
+/// Build WriteResult response from a write_file/write_chunk result
+pub fn write_result(path: String, result: VfsResult<u64>) -> NetworkMessage {
+    let (bytes_written, error) = match result {
+        Ok(written) => (written, None),
+        Err(VfsError::DiskFull { written, .. }) => (written, Some(WriteError::DiskFull)),
+        Err(VfsError::WritePartial { written, expected, .. }) => {
+            (written, Some(WriteError::WritePartial { expected }))
+        }
+        Err(VfsError::PermissionDenied(_)) => (0, Some(WriteError::PermissionDenied)),
+        Err(e) => (0, Some(WriteError::Io(e.to_string()))),
+    };
+
+    NetworkMessage::WriteResult { path, bytes_written, error }
+}
+
+/// Validate write target for security
+///
+/// The target may not exist yet, so its parent directory is checked against
+/// the jail; the target itself must not be a symlink or a non-regular file.
+pub fn validate_write_path(path: &Path, allowed_base: &Path) -> VfsResult<()> {
+    match path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        Some(parent) if path.file_name().is_some() => {
+            validate_path(parent, allowed_base)?;
+            check_write_target(path).map(|_| ())
+        }
+        _ => Err(VfsError::IoError(format!("Invalid file path: {}", path.display()))),
+    }
+}
+
+/// Maximum number of suggestions returned by path completion
+const MAX_COMPLETIONS: usize = 100;
+
+/// Complete the path token under the cursor
+///
+/// # Arguments
+/// * `line` - Full command line being edited
+/// * `cursor` - Cursor position in characters
+/// * `base` - Jail directory; relative tokens are resolved against it
+///
+/// Returns replacements for the whitespace-delimited token ending at the cursor.
+/// Directories get a trailing `/`. Hidden entries are only offered when the
+/// partial name starts with `.`.
+pub async fn complete_path(line: &str, cursor: usize, base: &Path) -> VfsResult<Vec<String>> {
+    let before_cursor: String = line.chars().take(cursor).collect();
+    let token = before_cursor.rsplit(char::is_whitespace).next().unwrap_or("");
+
+    // Split "src/ma" into directory part "src/" and partial name "ma"
+    let (dir_part, prefix) = match token.rfind('/') {
+        Some(idx) => (&token[..=idx], &token[idx + 1..]),
+        None => ("", token),
+    };
+
+    // Absolute tokens replace base on join, so validate_path rejects them outside the jail
+    let dir = base.join(dir_part);
+    validate_path(&dir, base)?;
+
+    let entries = read_directory(&dir).await?;
+
+    Ok(entries
+        .into_iter()
+        .filter(|e| e.name.starts_with(prefix))
+        .filter(|e| prefix.starts_with('.') || !e.name.starts_with('.'))
+        .take(MAX_COMPLETIONS)
+        .map(|e| format!("{}{}{}", dir_part, e.name, if e.is_dir { "/" } else { "" }))
+        .collect())
+}
+
 /// Validate path for security
 ///
 /// Uses canonicalize to resolve all symlinks and relative components.
@@ -184,6 +514,34 @@ pub fn validate_path(path: &Path, allowed_base: &Path) -> VfsResult<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::path::PathBuf;
+
+    /// Unique temp directory, removed on drop (also when an assertion fails)
+    struct TestDir(PathBuf);
+
+    impl TestDir {
+        fn new(name: &str) -> Self {
+            static COUNTER: AtomicUsize = AtomicUsize::new(0);
+            let path = std::env::temp_dir().join(format!(
+                "comacode_{}_{}_{}",
+                name,
+                std::process::id(),
+                COUNTER.fetch_add(1, Ordering::Relaxed)
+            ));
+            std::fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+
+        fn path(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TestDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
 
     #[test]
     fn test_validate_path_valid() {
@@ -223,4 +581,202 @@ mod tests {
         assert_eq!(chunks[0].len(), 3);
         assert_eq!(chunks[3].len(), 1); // last chunk has 1
     }
+
+    #[tokio::test]
+    async fn test_file_chunk_reader_total_size() {
+        let dir = TestDir::new("chunked");
+        let path = dir.path().join("data.bin");
+        let content: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+        std::fs::write(&path, &content).unwrap();
+
+        let mut reader = FileChunkReader::open(&path, 4096, 7).await.unwrap();
+        assert_eq!(reader.total_size(), 10_000);
+
+        let mut received = Vec::new();
+        let mut index = 0;
+        while let Some(msg) = reader.next_chunk().await.unwrap() {
+            match msg {
+                NetworkMessage::FileChunk { chunk_index, offset, data, total_size, has_more, request_id, .. } => {
+                    assert_eq!(request_id, 7);
+                    assert_eq!(chunk_index, index);
+                    assert_eq!(offset, received.len() as u64);
+                    // Only the first chunk carries the total size
+                    if index == 0 {
+                        assert_eq!(total_size, Some(10_000));
+                    } else {
+                        assert_eq!(total_size, None);
+                    }
+                    received.extend_from_slice(&data);
+                    assert_eq!(has_more, received.len() < content.len());
+                }
+                _ => panic!("Expected FileChunk"),
+            }
+            index += 1;
+        }
+
+        assert_eq!(index, 3); // 4096 + 4096 + 1808
+        assert_eq!(received, content);
+    }
+
+    /// Writer that accepts `limit` bytes, then fails every write with `kind`
+    struct FailingWriter {
+        limit: usize,
+        written: usize,
+        kind: ErrorKind,
+    }
+
+    impl Write for FailingWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            let n = buf.len().min(self.limit - self.written);
+            if n == 0 {
+                return match self.kind {
+                    ErrorKind::WriteZero => Ok(0),
+                    kind => Err(kind.into()),
+                };
+            }
+            self.written += n;
+            Ok(n)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Run `data` through `write_all_tracked` into a FailingWriter and classify the error
+    fn failed_write(limit: usize, kind: ErrorKind, data: &[u8]) -> VfsResult<u64> {
+        let mut writer = FailingWriter { limit, written: 0, kind };
+        match write_all_tracked(&mut writer, data) {
+            (written, Some(e)) => Err(write_error(e, Path::new("out.txt"), written, data.len() as u64)),
+            (written, None) => Ok(written),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_file_roundtrip() {
+        let dir = TestDir::new("write");
+        let path = dir.path().join("out.txt");
+
+        std::fs::write(&path, b"old content").unwrap();
+        assert_eq!(write_file(&path, b"new".to_vec()).await.unwrap(), 3);
+        assert_eq!(std::fs::read(&path).unwrap(), b"new");
+        // Temp file is renamed away
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+
+        assert_eq!(write_chunk(&path, 3, b" data".to_vec()).await.unwrap(), 5);
+        assert_eq!(std::fs::read(&path).unwrap(), b"new data");
+    }
+
+    #[tokio::test]
+    async fn test_write_file_concurrent_writes() {
+        let dir = TestDir::new("write_concurrent");
+        let path = dir.path().join("out.txt");
+
+        // Each write gets its own temp file, so none clobbers another
+        let writes = (0..8u8).map(|i| write_file(&path, vec![i; 4096]));
+        for result in futures::future::join_all(writes).await {
+            assert_eq!(result.unwrap(), 4096);
+        }
+
+        let content = std::fs::read(&path).unwrap();
+        assert_eq!(content.len(), 4096);
+        assert!(content.iter().all(|&b| b == content[0]));
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_write_refuses_symlink_target() {
+        let dir = TestDir::new("write_symlink");
+        let outside = TestDir::new("write_symlink_outside");
+        let secret = outside.path().join("secret");
+        std::fs::write(&secret, b"secret").unwrap();
+
+        let link = dir.path().join("link");
+        std::os::unix::fs::symlink(&secret, &link).unwrap();
+
+        assert!(matches!(validate_write_path(&link, dir.path()), Err(VfsError::PermissionDenied(_))));
+        assert!(matches!(write_file(&link, b"pwned".to_vec()).await, Err(VfsError::PermissionDenied(_))));
+        assert!(matches!(write_chunk(&link, 0, b"pwned".to_vec()).await, Err(VfsError::PermissionDenied(_))));
+        assert_eq!(std::fs::read(&secret).unwrap(), b"secret");
+        assert!(std::fs::symlink_metadata(&link).unwrap().file_type().is_symlink());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_write_refuses_device() {
+        let result = write_chunk(Path::new("/dev/null"), 0, b"data".to_vec()).await;
+        assert!(matches!(result, Err(VfsError::PermissionDenied(_))));
+        let result = write_file(Path::new("/dev/null"), b"data".to_vec()).await;
+        assert!(matches!(result, Err(VfsError::PermissionDenied(_))));
+    }
+
+    #[test]
+    fn test_write_error_disk_full() {
+        // ENOSPC after part of the data landed
+        let result = failed_write(1000, ErrorKind::StorageFull, &[0u8; 4096]);
+        assert!(matches!(result, Err(VfsError::DiskFull { written: 1000, .. })));
+
+        match write_result("out.txt".to_string(), result) {
+            NetworkMessage::WriteResult { bytes_written, error, .. } => {
+                assert_eq!(bytes_written, 1000);
+                assert_eq!(error, Some(WriteError::DiskFull));
+            }
+            _ => panic!("Expected WriteResult"),
+        }
+    }
+
+    #[test]
+    fn test_write_error_partial() {
+        let result = failed_write(100, ErrorKind::WriteZero, &[0u8; 300]);
+        assert!(matches!(result, Err(VfsError::WritePartial { written: 100, expected: 300, .. })));
+
+        match write_result("out.txt".to_string(), result) {
+            NetworkMessage::WriteResult { bytes_written, error, .. } => {
+                assert_eq!(bytes_written, 100);
+                assert_eq!(error, Some(WriteError::WritePartial { expected: 300 }));
+            }
+            _ => panic!("Expected WriteResult"),
+        }
+
+        assert_eq!(failed_write(300, ErrorKind::WriteZero, &[0u8; 300]).unwrap(), 300);
+    }
+
+    #[test]
+    fn test_write_error_permission_denied() {
+        // Read-only directory or filesystem; injected since root bypasses permissions
+        for kind in [ErrorKind::PermissionDenied, ErrorKind::ReadOnlyFilesystem] {
+            let result = failed_write(0, kind, b"hello");
+            assert!(matches!(result, Err(VfsError::PermissionDenied(_))));
+
+            match write_result("out.txt".to_string(), result) {
+                NetworkMessage::WriteResult { bytes_written, error, .. } => {
+                    assert_eq!(bytes_written, 0);
+                    assert_eq!(error, Some(WriteError::PermissionDenied));
+                }
+                _ => panic!("Expected WriteResult"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_complete_path_partial() {
+        let dir = TestDir::new("complete");
+        let base = dir.path();
+        let src = base.join("src");
+        std::fs::create_dir_all(src.join("macros")).unwrap();
+        std::fs::write(src.join("main.rs"), b"").unwrap();
+        std::fs::write(src.join("lib.rs"), b"").unwrap();
+        std::fs::write(src.join(".main.swp"), b"").unwrap();
+
+        let suggestions = complete_path("cat src/ma", 10, base).await.unwrap();
+        assert_eq!(suggestions, vec!["src/macros/".to_string(), "src/main.rs".to_string()]);
+
+        // Cursor in the middle of the line completes the token before it
+        let suggestions = complete_path("ls sr foo", 5, base).await.unwrap();
+        assert_eq!(suggestions, vec!["src/".to_string()]);
+
+        // Escaping the jail is rejected
+        assert!(complete_path("cat ../", 7, base).await.is_err());
+    }
 }
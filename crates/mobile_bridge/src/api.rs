@@ -9,7 +9,7 @@
 //! Phase VFS-3: File watcher API
 
 use comacode_core::{NetworkMessage, MessageCodec};
-use comacode_core::types::FileEventType;
+use comacode_core::types::{FileEventType, WriteError};
 use flutter_rust_bridge::frb;
 use once_cell::sync::OnceCell;
 use std::sync::Arc;
@@ -170,6 +170,36 @@ pub async fn resize_pty(rows: u16, cols: u16) -> Result<(), String> {
     client.resize_pty(rows, cols).await
 }
 
+/// Notify server that the app is going to background
+///
+/// Call from the app lifecycle handler (paused/inactive) before the OS
+/// suspends the process. Server holds terminal output until
+/// `enter_foreground` is called. Returns once the server has acknowledged
+/// the pause.
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized, or an error if the
+/// server does not acknowledge the pause within a second.
+#[frb]
+pub async fn enter_background() -> Result<(), String> {
+    let client_arc = get_client().await?;
+    let client = client_arc.lock().await;
+    client.enter_background().await
+}
+
+/// Notify server that the app is back in foreground
+///
+/// Resumes terminal output paused by `enter_background`.
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+#[frb]
+pub async fn enter_foreground() -> Result<(), String> {
+    let client_arc = get_client().await?;
+    let client = client_arc.lock().await;
+    client.enter_foreground().await
+}
+
 /// Disconnect from host
 ///
 /// Clears the client, allowing reconnect.
@@ -390,6 +420,17 @@ pub fn get_event_exit_code(event: &TerminalEvent) -> i32 {
     }
 }
 
+/// Get expected output charset (for SessionCreated events)
+///
+/// Returns "UTF-8" for other events.
+#[frb(sync)]
+pub fn get_event_charset(event: &TerminalEvent) -> String {
+    match event {
+        TerminalEvent::SessionCreated { charset, .. } => charset.clone(),
+        _ => "UTF-8".to_string(),
+    }
+}
+
 /// Check if event is Output
 #[frb(sync)]
 pub fn is_event_output(event: &TerminalEvent) -> bool {
@@ -842,6 +883,193 @@ pub async fn file_content_buffer_len() -> Result<usize, String> {
     Ok(client.file_content_buffer_len().await)
 }
 
+/// Request server to read a large file in chunks
+///
+/// Server responds with file chunks. Call receive_file_chunk() in a loop
+/// until `has_more` is false. A new request replaces any download still in
+/// progress; its remaining chunks are never returned.
+///
+/// # Arguments
+/// * `path` - Absolute path to file
+/// * `chunk_size` - Bytes per chunk (server clamps to 4KB..1MB, default: 65536)
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+#[frb]
+pub async fn request_read_file_chunked(path: String, chunk_size: u32) -> Result<(), String> {
+    tracing::info!("📄 [FRB] request_read_file_chunked: {} (chunk_size: {})", path, chunk_size);
+    let client_arc = get_client().await?;
+    let client = client_arc.lock().await;
+    client.request_read_file_chunked(path, chunk_size).await
+}
+
+/// File chunk data with download progress (for Dart)
+#[derive(Debug, Clone, Default)]
+#[frb(sync)]
+pub struct FileChunkData {
+    /// File path
+    pub path: String,
+    /// Chunk index (0-based)
+    pub chunk_index: u32,
+    /// Raw chunk bytes
+    pub data: Vec<u8>,
+    /// True if more chunks follow
+    pub has_more: bool,
+    /// Total bytes received so far
+    pub received_bytes: u64,
+    /// File size on disk (from first chunk)
+    pub total_size: u64,
+}
+
+/// Receive next file chunk from server (NON-BLOCKING)
+///
+/// Progress for a download is `received_bytes / total_size`.
+///
+/// # Returns
+/// * `Some(FileChunkData)` - Chunk received, with cumulative progress
+/// * `None` - No chunk available yet
+///
+/// Only a few chunks are queued; the download waits until they are taken,
+/// without holding up terminal output.
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized, or the failure of a
+/// download that broke off.
+#[frb]
+pub async fn receive_file_chunk() -> Result<Option<FileChunkData>, String> {
+    let client_arc = get_client().await?;
+    let client = client_arc.lock().await;
+
+    match client.receive_file_chunk().await? {
+        Some((path, chunk_index, data, has_more)) => {
+            let (received_bytes, total_size) = client.file_read_progress().await;
+            Ok(Some(FileChunkData {
+                path,
+                chunk_index,
+                data,
+                has_more,
+                received_bytes,
+                total_size,
+            }))
+        }
+        None => Ok(None),
+    }
+}
+
+// ===== VFS File Writing Functions =====
+
+/// Request server to write a whole file
+///
+/// Host writes to a temp file and renames it, so the target is untouched on
+/// failure. Call receive_write_result() to get the outcome.
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+#[frb]
+pub async fn request_write_file(path: String, data: Vec<u8>) -> Result<(), String> {
+    tracing::info!("📝 [FRB] request_write_file: {} ({} bytes)", path, data.len());
+    let client_arc = get_client().await?;
+    let client = client_arc.lock().await;
+    client.request_write_file(path, data).await
+}
+
+/// Request server to write data at offset (chunked upload)
+///
+/// Server sends one write result per chunk. On `write_partial`, resend the
+/// rest starting at `offset + bytes_written`.
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+#[frb]
+pub async fn request_write_chunk(path: String, offset: u64, data: Vec<u8>) -> Result<(), String> {
+    let client_arc = get_client().await?;
+    let client = client_arc.lock().await;
+    client.request_write_chunk(path, offset, data).await
+}
+
+/// Write result data (for Dart)
+#[derive(Debug, Clone, Default)]
+#[frb(sync)]
+pub struct WriteResultData {
+    /// File path
+    pub path: String,
+    /// Bytes actually written (less than requested on failure)
+    pub bytes_written: u64,
+    /// Error kind: "" (success), "disk_full", "write_partial", "permission_denied", or "io"
+    pub error_kind: String,
+    /// Human-readable error message (empty on success)
+    pub error: String,
+}
+
+/// Receive next write result from server (NON-BLOCKING)
+///
+/// # Returns
+/// * `Some(WriteResultData)` - Write result received
+/// * `None` - No result available yet
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+#[frb]
+pub async fn receive_write_result() -> Result<Option<WriteResultData>, String> {
+    let client_arc = get_client().await?;
+    let client = client_arc.lock().await;
+
+    match client.receive_write_result().await? {
+        Some((path, bytes_written, error)) => Ok(Some(WriteResultData {
+            path,
+            bytes_written,
+            error_kind: match error {
+                None => String::new(),
+                Some(WriteError::DiskFull) => "disk_full".to_string(),
+                Some(WriteError::WritePartial { .. }) => "write_partial".to_string(),
+                Some(WriteError::PermissionDenied) => "permission_denied".to_string(),
+                Some(WriteError::Io(_)) => "io".to_string(),
+            },
+            error: error.map(|e| e.to_string()).unwrap_or_default(),
+        })),
+        None => Ok(None),
+    }
+}
+
+// ===== Command Completion =====
+
+/// Request path completion for the token under the cursor (Tab key)
+///
+/// Server completes against the session's project directory.
+/// Call receive_completion() to get the suggestions.
+///
+/// # Arguments
+/// * `session_id` - Active session UUID
+/// * `line` - Current command line
+/// * `cursor` - Cursor position in characters
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+#[frb]
+pub async fn request_completion(session_id: String, line: String, cursor: usize) -> Result<(), String> {
+    let client_arc = get_client().await?;
+    let client = client_arc.lock().await;
+    client.request_completion(session_id, line, cursor).await
+}
+
+/// Receive completion suggestions (NON-BLOCKING)
+///
+/// Each suggestion replaces the whole token under the cursor.
+/// Directories end with `/`.
+///
+/// # Returns
+/// * `Some(suggestions)` - Suggestions received (may be empty)
+/// * `None` - No response yet
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+#[frb]
+pub async fn receive_completion() -> Result<Option<Vec<String>>, String> {
+    let client_arc = get_client().await?;
+    let client = client_arc.lock().await;
+    Ok(client.receive_completion().await)
+}
+
 // ===== Multi-Session Management - Phase 04 =====
 
 /// Create a new PTY session with UUID
@@ -859,7 +1087,64 @@ pub async fn create_session(project_path: String, session_id: String) -> Result<
     tracing::info!("📝 [FRB] create_session: {} at {}", session_id, project_path);
     let client_arc = get_client().await?;
     let client = client_arc.lock().await;
-    client.create_session(project_path, session_id).await
+    client.create_session(project_path, session_id, None, None).await
+}
+
+/// Create a new PTY session with explicit locale and/or shell
+///
+/// Same as `create_session`, but sets LANG/LC_ALL and the shell on the host
+/// PTY. Server reports the resulting charset in the SessionCreated event
+/// (see `get_event_charset`).
+///
+/// # Arguments
+/// * `project_path` - Absolute path to project directory
+/// * `session_id` - UUID string for the session
+/// * `locale` - Locale name, e.g. "vi_VN.UTF-8" (charset defaults to UTF-8)
+/// * `shell` - Shell path from `receive_shell_list()`; the host rejects others
+///   with an Error event and creates no session
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+#[frb]
+pub async fn create_session_with_options(
+    project_path: String,
+    session_id: String,
+    locale: Option<String>,
+    shell: Option<String>,
+) -> Result<(), String> {
+    tracing::info!("📝 [FRB] create_session_with_options: {} at {} (locale: {:?}, shell: {:?})",
+        session_id, project_path, locale, shell);
+    let client_arc = get_client().await?;
+    let client = client_arc.lock().await;
+    client.create_session(project_path, session_id, locale, shell).await
+}
+
+/// Request list of shells installed on the host
+///
+/// Server responds with the shell list. Call receive_shell_list() to get it.
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+#[frb]
+pub async fn request_shells() -> Result<(), String> {
+    let client_arc = get_client().await?;
+    let client = client_arc.lock().await;
+    client.request_shells().await
+}
+
+/// Receive host shell list (NON-BLOCKING)
+///
+/// # Returns
+/// * `Some(shells)` - Shell paths, usable as `shell` in `create_session_with_options`
+/// * `None` - No response yet
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+#[frb]
+pub async fn receive_shell_list() -> Result<Option<Vec<String>>, String> {
+    let client_arc = get_client().await?;
+    let client = client_arc.lock().await;
+    Ok(client.receive_shell_list().await)
 }
 
 /// Check if session exists on server (for re-attach on app restart)
@@ -38,7 +38,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -96756891;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 815290612;
 
 // Section: executor
 
@@ -262,6 +262,51 @@ fn wire__crate__api__create_session_impl(
         },
     )
 }
+fn wire__crate__api__create_session_with_options_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_session_with_options",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project_path = <String>::sse_decode(&mut deserializer);
+            let api_session_id = <String>::sse_decode(&mut deserializer);
+            let api_locale = <Option<String>>::sse_decode(&mut deserializer);
+            let api_shell = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::create_session_with_options(
+                            api_project_path,
+                            api_session_id,
+                            api_locale,
+                            api_shell,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__create_terminal_config_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -509,6 +554,76 @@ fn wire__crate__api__encode_resize_impl(
         },
     )
 }
+fn wire__crate__api__enter_background_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "enter_background",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::enter_background().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__enter_foreground_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "enter_foreground",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::enter_foreground().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__event_output_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1114,6 +1229,53 @@ fn wire__crate__api__get_dir_entry_size_impl(
         },
     )
 }
+fn wire__crate__api__get_event_charset_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_event_charset",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_event = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TerminalEvent>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_event_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_event, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_event_guard = Some(api_event.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_event_guard = api_event_guard.unwrap();
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::get_event_charset(&*api_event_guard))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__get_event_data_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1898,7 +2060,7 @@ fn wire__crate__api__parse_qr_payload_impl(
         },
     )
 }
-fn wire__crate__api__receive_dir_chunk_impl(
+fn wire__crate__api__receive_completion_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1906,7 +2068,7 @@ fn wire__crate__api__receive_dir_chunk_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "receive_dir_chunk",
+            debug_name: "receive_completion",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1924,7 +2086,7 @@ fn wire__crate__api__receive_dir_chunk_impl(
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::receive_dir_chunk().await?;
+                        let output_ok = crate::api::receive_completion().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1933,7 +2095,7 @@ fn wire__crate__api__receive_dir_chunk_impl(
         },
     )
 }
-fn wire__crate__api__receive_file_content_impl(
+fn wire__crate__api__receive_dir_chunk_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1941,7 +2103,7 @@ fn wire__crate__api__receive_file_content_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "receive_file_content",
+            debug_name: "receive_dir_chunk",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1959,7 +2121,7 @@ fn wire__crate__api__receive_file_content_impl(
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::receive_file_content().await?;
+                        let output_ok = crate::api::receive_dir_chunk().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1968,7 +2130,7 @@ fn wire__crate__api__receive_file_content_impl(
         },
     )
 }
-fn wire__crate__api__receive_file_event_impl(
+fn wire__crate__api__receive_file_chunk_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1976,7 +2138,7 @@ fn wire__crate__api__receive_file_event_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "receive_file_event",
+            debug_name: "receive_file_chunk",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1994,7 +2156,7 @@ fn wire__crate__api__receive_file_event_impl(
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::receive_file_event().await?;
+                        let output_ok = crate::api::receive_file_chunk().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2003,7 +2165,7 @@ fn wire__crate__api__receive_file_event_impl(
         },
     )
 }
-fn wire__crate__api__receive_session_history_impl(
+fn wire__crate__api__receive_file_content_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2011,7 +2173,7 @@ fn wire__crate__api__receive_session_history_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "receive_session_history",
+            debug_name: "receive_file_content",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2029,7 +2191,7 @@ fn wire__crate__api__receive_session_history_impl(
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::receive_session_history().await?;
+                        let output_ok = crate::api::receive_file_content().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2038,7 +2200,7 @@ fn wire__crate__api__receive_session_history_impl(
         },
     )
 }
-fn wire__crate__api__receive_terminal_event_impl(
+fn wire__crate__api__receive_file_event_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2046,7 +2208,7 @@ fn wire__crate__api__receive_terminal_event_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "receive_terminal_event",
+            debug_name: "receive_file_event",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2064,7 +2226,7 @@ fn wire__crate__api__receive_terminal_event_impl(
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::receive_terminal_event().await?;
+                        let output_ok = crate::api::receive_file_event().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2073,7 +2235,7 @@ fn wire__crate__api__receive_terminal_event_impl(
         },
     )
 }
-fn wire__crate__api__request_list_dir_impl(
+fn wire__crate__api__receive_session_history_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2081,7 +2243,7 @@ fn wire__crate__api__request_list_dir_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "request_list_dir",
+            debug_name: "receive_session_history",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2095,12 +2257,11 @@ fn wire__crate__api__request_list_dir_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::request_list_dir(api_path).await?;
+                        let output_ok = crate::api::receive_session_history().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2109,7 +2270,7 @@ fn wire__crate__api__request_list_dir_impl(
         },
     )
 }
-fn wire__crate__api__request_read_file_impl(
+fn wire__crate__api__receive_shell_list_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2117,7 +2278,7 @@ fn wire__crate__api__request_read_file_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "request_read_file",
+            debug_name: "receive_shell_list",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2131,14 +2292,11 @@ fn wire__crate__api__request_read_file_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
-            let api_max_size = <usize>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::request_read_file(api_path, api_max_size).await?;
+                        let output_ok = crate::api::receive_shell_list().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2147,7 +2305,7 @@ fn wire__crate__api__request_read_file_impl(
         },
     )
 }
-fn wire__crate__api__request_unwatch_dir_impl(
+fn wire__crate__api__receive_terminal_event_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2155,7 +2313,7 @@ fn wire__crate__api__request_unwatch_dir_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "request_unwatch_dir",
+            debug_name: "receive_terminal_event",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2169,12 +2327,11 @@ fn wire__crate__api__request_unwatch_dir_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_watcher_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::request_unwatch_dir(api_watcher_id).await?;
+                        let output_ok = crate::api::receive_terminal_event().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2183,7 +2340,7 @@ fn wire__crate__api__request_unwatch_dir_impl(
         },
     )
 }
-fn wire__crate__api__request_watch_dir_impl(
+fn wire__crate__api__receive_write_result_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2191,7 +2348,7 @@ fn wire__crate__api__request_watch_dir_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "request_watch_dir",
+            debug_name: "receive_write_result",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2205,12 +2362,11 @@ fn wire__crate__api__request_watch_dir_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::request_watch_dir(api_path).await?;
+                        let output_ok = crate::api::receive_write_result().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2219,7 +2375,7 @@ fn wire__crate__api__request_watch_dir_impl(
         },
     )
 }
-fn wire__crate__api__resize_pty_impl(
+fn wire__crate__api__request_completion_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2227,7 +2383,7 @@ fn wire__crate__api__resize_pty_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "resize_pty",
+            debug_name: "request_completion",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2241,13 +2397,16 @@ fn wire__crate__api__resize_pty_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_rows = <u16>::sse_decode(&mut deserializer);
-            let api_cols = <u16>::sse_decode(&mut deserializer);
+            let api_session_id = <String>::sse_decode(&mut deserializer);
+            let api_line = <String>::sse_decode(&mut deserializer);
+            let api_cursor = <usize>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::resize_pty(api_rows, api_cols).await?;
+                        let output_ok =
+                            crate::api::request_completion(api_session_id, api_line, api_cursor)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2256,7 +2415,7 @@ fn wire__crate__api__resize_pty_impl(
         },
     )
 }
-fn wire__crate__api__send_raw_input_impl(
+fn wire__crate__api__request_list_dir_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2264,7 +2423,7 @@ fn wire__crate__api__send_raw_input_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_raw_input",
+            debug_name: "request_list_dir",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2278,12 +2437,12 @@ fn wire__crate__api__send_raw_input_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::send_raw_input(api_data).await?;
+                        let output_ok = crate::api::request_list_dir(api_path).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2292,7 +2451,7 @@ fn wire__crate__api__send_raw_input_impl(
         },
     )
 }
-fn wire__crate__api__send_terminal_command_impl(
+fn wire__crate__api__request_read_file_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2300,7 +2459,7 @@ fn wire__crate__api__send_terminal_command_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_terminal_command",
+            debug_name: "request_read_file",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2314,12 +2473,14 @@ fn wire__crate__api__send_terminal_command_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_command = <String>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_max_size = <usize>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::send_terminal_command(api_command).await?;
+                        let output_ok =
+                            crate::api::request_read_file(api_path, api_max_size).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2328,7 +2489,7 @@ fn wire__crate__api__send_terminal_command_impl(
         },
     )
 }
-fn wire__crate__api__send_vibe_input_impl(
+fn wire__crate__api__request_read_file_chunked_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2336,7 +2497,7 @@ fn wire__crate__api__send_vibe_input_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_vibe_input",
+            debug_name: "request_read_file_chunked",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2350,14 +2511,14 @@ fn wire__crate__api__send_vibe_input_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api__session_id = <String>::sse_decode(&mut deserializer);
-            let api_input = <crate::api::VibeInput>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_chunk_size = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
                         let output_ok =
-                            crate::api::send_vibe_input(api__session_id, api_input).await?;
+                            crate::api::request_read_file_chunked(api_path, api_chunk_size).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2366,7 +2527,7 @@ fn wire__crate__api__send_vibe_input_impl(
         },
     )
 }
-fn wire__crate__api__session_command_impl(
+fn wire__crate__api__request_shells_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2374,7 +2535,7 @@ fn wire__crate__api__session_command_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "session_command",
+            debug_name: "request_shells",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2388,12 +2549,11 @@ fn wire__crate__api__session_command_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_cmd = <crate::api::SessionCommand>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::session_command(api_cmd).await?;
+                        let output_ok = crate::api::request_shells().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2402,23 +2562,7 @@ fn wire__crate__api__session_command_impl(
         },
     )
 }
-fn wire__crate__api__stream_list_dir_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "stream_list_dir", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
-let api_sink = <StreamSink<Vec<DirEntry>,flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
-                    transform_result_sse::<_, String>((move ||  {
-                         let output_ok = crate::api::stream_list_dir(api_path, api_sink)?;   Ok(output_ok)
-                    })())
-                } })
-}
-fn wire__crate__api__switch_session_impl(
+fn wire__crate__api__request_unwatch_dir_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2426,7 +2570,7 @@ fn wire__crate__api__switch_session_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "switch_session",
+            debug_name: "request_unwatch_dir",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2440,12 +2584,12 @@ fn wire__crate__api__switch_session_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_session_id = <String>::sse_decode(&mut deserializer);
+            let api_watcher_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::switch_session(api_session_id).await?;
+                        let output_ok = crate::api::request_unwatch_dir(api_watcher_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2454,15 +2598,362 @@ fn wire__crate__api__switch_session_impl(
         },
     )
 }
-fn wire__crate__api__terminal_config_default_impl(
+fn wire__crate__api__request_watch_dir_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "terminal_config_default",
+            debug_name: "request_watch_dir",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::request_watch_dir(api_path).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__request_write_chunk_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "request_write_chunk",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_offset = <u64>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::request_write_chunk(api_path, api_offset, api_data).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__request_write_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "request_write_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::request_write_file(api_path, api_data).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__resize_pty_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resize_pty",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_rows = <u16>::sse_decode(&mut deserializer);
+            let api_cols = <u16>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::resize_pty(api_rows, api_cols).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__send_raw_input_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_raw_input",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::send_raw_input(api_data).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__send_terminal_command_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_terminal_command",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_command = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::send_terminal_command(api_command).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__send_vibe_input_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_vibe_input",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api__session_id = <String>::sse_decode(&mut deserializer);
+            let api_input = <crate::api::VibeInput>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::send_vibe_input(api__session_id, api_input).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__session_command_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "session_command",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_cmd = <crate::api::SessionCommand>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::session_command(api_cmd).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__stream_list_dir_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "stream_list_dir", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+let api_sink = <StreamSink<Vec<DirEntry>,flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, String>((move ||  {
+                         let output_ok = crate::api::stream_list_dir(api_path, api_sink)?;   Ok(output_ok)
+                    })())
+                } })
+}
+fn wire__crate__api__switch_session_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "switch_session",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::switch_session(api_session_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__terminal_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "terminal_config_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2615,6 +3106,26 @@ impl SseDecode for bool {
     }
 }
 
+impl SseDecode for crate::api::FileChunkData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_chunkIndex = <u32>::sse_decode(deserializer);
+        let mut var_data = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_hasMore = <bool>::sse_decode(deserializer);
+        let mut var_receivedBytes = <u64>::sse_decode(deserializer);
+        let mut var_totalSize = <u64>::sse_decode(deserializer);
+        return crate::api::FileChunkData {
+            path: var_path,
+            chunk_index: var_chunkIndex,
+            data: var_data,
+            has_more: var_hasMore,
+            received_bytes: var_receivedBytes,
+            total_size: var_totalSize,
+        };
+    }
+}
+
 impl SseDecode for crate::api::FileContentData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2719,6 +3230,17 @@ impl SseDecode for Option<String> {
     }
 }
 
+impl SseDecode for Option<crate::api::FileChunkData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::FileChunkData>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<crate::api::FileContentData> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2774,6 +3296,28 @@ impl SseDecode for Option<u64> {
     }
 }
 
+impl SseDecode for Option<crate::api::WriteResultData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::WriteResultData>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<String>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for (u32, Vec<DirEntry>, bool) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2927,6 +3471,22 @@ impl SseDecode for crate::api::VibeInput {
     }
 }
 
+impl SseDecode for crate::api::WriteResultData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_bytesWritten = <u64>::sse_decode(deserializer);
+        let mut var_errorKind = <String>::sse_decode(deserializer);
+        let mut var_error = <String>::sse_decode(deserializer);
+        return crate::api::WriteResultData {
+            path: var_path,
+            bytes_written: var_bytesWritten,
+            error_kind: var_errorKind,
+            error: var_error,
+        };
+    }
+}
+
 fn pde_ffi_dispatcher_primary_impl(
     func_id: i32,
     port: flutter_rust_bridge::for_generated::MessagePort,
@@ -2940,43 +3500,55 @@ fn pde_ffi_dispatcher_primary_impl(
         3 => wire__crate__api__close_session_impl(port, ptr, rust_vec_len, data_len),
         4 => wire__crate__api__connect_to_host_impl(port, ptr, rust_vec_len, data_len),
         6 => wire__crate__api__create_session_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__decode_message_impl(port, ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__disconnect_from_host_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__encode_command_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__encode_input_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__encode_ping_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__encode_resize_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__file_content_buffer_len_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__file_content_data_default_impl(port, ptr, rust_vec_len, data_len),
-        18 => wire__crate__api__file_event_buffer_len_impl(port, ptr, rust_vec_len, data_len),
-        19 => wire__crate__api__file_watcher_event_data_default_impl(
+        7 => wire__crate__api__create_session_with_options_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__decode_message_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__disconnect_from_host_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__encode_command_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__encode_input_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__encode_ping_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__encode_resize_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__enter_background_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__enter_foreground_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__file_content_buffer_len_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__file_content_data_default_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__file_event_buffer_len_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__file_watcher_event_data_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        20 => wire__crate__api__get_active_session_id_impl(port, ptr, rust_vec_len, data_len),
-        38 => wire__crate__api__is_connected_impl(port, ptr, rust_vec_len, data_len),
-        44 => wire__crate__api__list_directory_impl(port, ptr, rust_vec_len, data_len),
-        45 => wire__crate__api__list_sessions_impl(port, ptr, rust_vec_len, data_len),
-        46 => wire__crate__api__parse_qr_payload_impl(port, ptr, rust_vec_len, data_len),
-        47 => wire__crate__api__receive_dir_chunk_impl(port, ptr, rust_vec_len, data_len),
-        48 => wire__crate__api__receive_file_content_impl(port, ptr, rust_vec_len, data_len),
-        49 => wire__crate__api__receive_file_event_impl(port, ptr, rust_vec_len, data_len),
-        50 => wire__crate__api__receive_session_history_impl(port, ptr, rust_vec_len, data_len),
-        51 => wire__crate__api__receive_terminal_event_impl(port, ptr, rust_vec_len, data_len),
-        52 => wire__crate__api__request_list_dir_impl(port, ptr, rust_vec_len, data_len),
-        53 => wire__crate__api__request_read_file_impl(port, ptr, rust_vec_len, data_len),
-        54 => wire__crate__api__request_unwatch_dir_impl(port, ptr, rust_vec_len, data_len),
-        55 => wire__crate__api__request_watch_dir_impl(port, ptr, rust_vec_len, data_len),
-        56 => wire__crate__api__resize_pty_impl(port, ptr, rust_vec_len, data_len),
-        57 => wire__crate__api__send_raw_input_impl(port, ptr, rust_vec_len, data_len),
-        58 => wire__crate__api__send_terminal_command_impl(port, ptr, rust_vec_len, data_len),
-        59 => wire__crate__api__send_vibe_input_impl(port, ptr, rust_vec_len, data_len),
-        60 => wire__crate__api__session_command_impl(port, ptr, rust_vec_len, data_len),
-        61 => wire__crate__api__stream_list_dir_impl(port, ptr, rust_vec_len, data_len),
-        62 => wire__crate__api__switch_session_impl(port, ptr, rust_vec_len, data_len),
-        63 => wire__crate__api__terminal_config_default_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__get_active_session_id_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__is_connected_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__list_directory_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__list_sessions_impl(port, ptr, rust_vec_len, data_len),
+        50 => wire__crate__api__parse_qr_payload_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__receive_completion_impl(port, ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__receive_dir_chunk_impl(port, ptr, rust_vec_len, data_len),
+        53 => wire__crate__api__receive_file_chunk_impl(port, ptr, rust_vec_len, data_len),
+        54 => wire__crate__api__receive_file_content_impl(port, ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__receive_file_event_impl(port, ptr, rust_vec_len, data_len),
+        56 => wire__crate__api__receive_session_history_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__receive_shell_list_impl(port, ptr, rust_vec_len, data_len),
+        58 => wire__crate__api__receive_terminal_event_impl(port, ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__receive_write_result_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__request_completion_impl(port, ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__request_list_dir_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__request_read_file_impl(port, ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__request_read_file_chunked_impl(port, ptr, rust_vec_len, data_len),
+        64 => wire__crate__api__request_shells_impl(port, ptr, rust_vec_len, data_len),
+        65 => wire__crate__api__request_unwatch_dir_impl(port, ptr, rust_vec_len, data_len),
+        66 => wire__crate__api__request_watch_dir_impl(port, ptr, rust_vec_len, data_len),
+        67 => wire__crate__api__request_write_chunk_impl(port, ptr, rust_vec_len, data_len),
+        68 => wire__crate__api__request_write_file_impl(port, ptr, rust_vec_len, data_len),
+        69 => wire__crate__api__resize_pty_impl(port, ptr, rust_vec_len, data_len),
+        70 => wire__crate__api__send_raw_input_impl(port, ptr, rust_vec_len, data_len),
+        71 => wire__crate__api__send_terminal_command_impl(port, ptr, rust_vec_len, data_len),
+        72 => wire__crate__api__send_vibe_input_impl(port, ptr, rust_vec_len, data_len),
+        73 => wire__crate__api__session_command_impl(port, ptr, rust_vec_len, data_len),
+        74 => wire__crate__api__stream_list_dir_impl(port, ptr, rust_vec_len, data_len),
+        75 => wire__crate__api__switch_session_impl(port, ptr, rust_vec_len, data_len),
+        76 => wire__crate__api__terminal_config_default_impl(port, ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -2991,31 +3563,32 @@ fn pde_ffi_dispatcher_sync_impl(
     match func_id {
         1 => wire__crate__api__add_impl(ptr, rust_vec_len, data_len),
         5 => wire__crate__api__create_command_impl(ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__create_terminal_config_impl(ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__event_output_impl(ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__event_output_str_impl(ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__get_command_id_impl(ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__get_command_text_impl(ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__get_command_timestamp_impl(ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__get_dir_entry_modified_impl(ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__get_dir_entry_name_impl(ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__get_dir_entry_path_impl(ptr, rust_vec_len, data_len),
-        27 => wire__crate__api__get_dir_entry_permissions_impl(ptr, rust_vec_len, data_len),
-        28 => wire__crate__api__get_dir_entry_size_impl(ptr, rust_vec_len, data_len),
-        29 => wire__crate__api__get_event_data_impl(ptr, rust_vec_len, data_len),
-        30 => wire__crate__api__get_event_error_message_impl(ptr, rust_vec_len, data_len),
-        31 => wire__crate__api__get_event_exit_code_impl(ptr, rust_vec_len, data_len),
-        32 => wire__crate__api__get_qr_fingerprint_impl(ptr, rust_vec_len, data_len),
-        33 => wire__crate__api__get_qr_ip_impl(ptr, rust_vec_len, data_len),
-        34 => wire__crate__api__get_qr_port_impl(ptr, rust_vec_len, data_len),
-        35 => wire__crate__api__get_qr_protocol_version_impl(ptr, rust_vec_len, data_len),
-        36 => wire__crate__api__get_qr_token_impl(ptr, rust_vec_len, data_len),
-        37 => wire__crate__api__greet_impl(ptr, rust_vec_len, data_len),
-        39 => wire__crate__api__is_dir_entry_dir_impl(ptr, rust_vec_len, data_len),
-        40 => wire__crate__api__is_dir_entry_symlink_impl(ptr, rust_vec_len, data_len),
-        41 => wire__crate__api__is_event_error_impl(ptr, rust_vec_len, data_len),
-        42 => wire__crate__api__is_event_exit_impl(ptr, rust_vec_len, data_len),
-        43 => wire__crate__api__is_event_output_impl(ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__create_terminal_config_impl(ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__event_output_impl(ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__event_output_str_impl(ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__get_command_id_impl(ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__get_command_text_impl(ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__get_command_timestamp_impl(ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__get_dir_entry_modified_impl(ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__get_dir_entry_name_impl(ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__get_dir_entry_path_impl(ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__get_dir_entry_permissions_impl(ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__get_dir_entry_size_impl(ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__get_event_charset_impl(ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__get_event_data_impl(ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__get_event_error_message_impl(ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__get_event_exit_code_impl(ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__get_qr_fingerprint_impl(ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__get_qr_ip_impl(ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__get_qr_port_impl(ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__get_qr_protocol_version_impl(ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__get_qr_token_impl(ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__greet_impl(ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__is_dir_entry_dir_impl(ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__is_dir_entry_symlink_impl(ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__is_event_error_impl(ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__is_event_exit_impl(ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__is_event_output_impl(ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -3082,6 +3655,26 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<TerminalEvent>> for TerminalEv
     }
 }
 
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::FileChunkData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.chunk_index.into_into_dart().into_dart(),
+            self.data.into_into_dart().into_dart(),
+            self.has_more.into_into_dart().into_dart(),
+            self.received_bytes.into_into_dart().into_dart(),
+            self.total_size.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::FileChunkData {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::FileChunkData> for crate::api::FileChunkData {
+    fn into_into_dart(self) -> crate::api::FileChunkData {
+        self
+    }
+}
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::FileContentData {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
@@ -3241,6 +3834,26 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::VibeInput> for crate::api::Vi
         self
     }
 }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WriteResultData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.bytes_written.into_into_dart().into_dart(),
+            self.error_kind.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WriteResultData {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WriteResultData>
+    for crate::api::WriteResultData
+{
+    fn into_into_dart(self) -> crate::api::WriteResultData {
+        self
+    }
+}
 
 impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3342,6 +3955,18 @@ impl SseEncode for bool {
     }
 }
 
+impl SseEncode for crate::api::FileChunkData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <u32>::sse_encode(self.chunk_index, serializer);
+        <Vec<u8>>::sse_encode(self.data, serializer);
+        <bool>::sse_encode(self.has_more, serializer);
+        <u64>::sse_encode(self.received_bytes, serializer);
+        <u64>::sse_encode(self.total_size, serializer);
+    }
+}
+
 impl SseEncode for crate::api::FileContentData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3422,6 +4047,16 @@ impl SseEncode for Option<String> {
     }
 }
 
+impl SseEncode for Option<crate::api::FileChunkData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::FileChunkData>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::api::FileContentData> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3472,6 +4107,26 @@ impl SseEncode for Option<u64> {
     }
 }
 
+impl SseEncode for Option<crate::api::WriteResultData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::WriteResultData>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<String>>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for (u32, Vec<DirEntry>, bool) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3604,6 +4259,16 @@ impl SseEncode for crate::api::VibeInput {
     }
 }
 
+impl SseEncode for crate::api::WriteResultData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <u64>::sse_encode(self.bytes_written, serializer);
+        <String>::sse_encode(self.error_kind, serializer);
+        <String>::sse_encode(self.error, serializer);
+    }
+}
+
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.
@@ -16,10 +16,12 @@
 use comacode_core::{TerminalEvent, AuthToken};
 use comacode_core::types::DirEntry;
 use comacode_core::protocol::MessageCodec;
-use comacode_core::types::{NetworkMessage, TerminalCommand, FileEventType, SessionMessage, TaggedOutput};
-use quinn::{Endpoint, Connection, SendStream};
+use comacode_core::types::{NetworkMessage, TerminalCommand, FileEventType, SessionMessage, TaggedOutput, WriteError};
+use quinn::{Endpoint, Connection, RecvStream, SendStream};
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::{mpsc, watch, Mutex};
 use tokio::task::JoinHandle;
 use tracing::{info, error, debug, warn};
 use bytes::{BytesMut, BufMut, Buf};
@@ -33,6 +35,15 @@ use rustls_pki_types::{CertificateDer, ServerName, UnixTime};
 // SHA256 for fingerprint calculation
 use sha2::{Digest, Sha256};
 
+/// FileChunk messages queued before the download stream waits for the app
+const FILE_CHUNK_QUEUE: usize = 8;
+
+/// Largest message accepted from the server
+const MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
+
+/// How long enter_background() waits for the host to echo the pause
+const FLOW_CONTROL_ACK_TIMEOUT: Duration = Duration::from_secs(1);
+
 /// Custom certificate verifier for TOFU (Trust On First Use)
 ///
 /// This verifier:
@@ -172,6 +183,8 @@ pub struct QuicClient {
     send_stream: Option<Arc<Mutex<SendStream>>>,
     /// Background task for receiving terminal events
     recv_task: Option<JoinHandle<()>>,
+    /// Background task accepting FileChunk streams (one per download)
+    file_chunk_task: Option<JoinHandle<()>>,
     /// Event buffer for background receive task
     /// Events from server are pushed here by background task
     event_buffer: Arc<Mutex<Vec<TerminalEvent>>>,
@@ -181,6 +194,26 @@ pub struct QuicClient {
     file_event_buffer: Arc<Mutex<Vec<NetworkMessage>>>,
     /// File content buffer for VFS file reading (Phase VFS-2)
     file_content_buffer: Arc<Mutex<Vec<NetworkMessage>>>,
+    /// FileChunk queue for chunked file reading
+    /// Bounded: when full, the download stream stops being read, so QUIC
+    /// flow control slows the server instead of dropping file data
+    file_chunk_tx: mpsc::Sender<NetworkMessage>,
+    file_chunk_rx: Arc<Mutex<mpsc::Receiver<NetworkMessage>>>,
+    /// request_id of the current chunked download; chunks of older ones are dropped
+    file_read_request: Arc<AtomicU32>,
+    /// Set when a chunked download failed; returned by receive_file_chunk()
+    /// and cleared by the next request
+    file_read_error: Arc<Mutex<Option<String>>>,
+    /// Chunked read progress: (received bytes, total size from first chunk)
+    file_read_progress: Arc<Mutex<(u64, u64)>>,
+    /// WriteResult buffer for VFS file writing
+    write_result_buffer: Arc<Mutex<Vec<NetworkMessage>>>,
+    /// Latest ShellList response (None until received)
+    shell_list: Arc<Mutex<Option<Vec<String>>>>,
+    /// Latest CompleteResult suggestions (None until received)
+    completion_result: Arc<Mutex<Option<Vec<String>>>>,
+    /// Last FlowControl state echoed by the host (None until acknowledged)
+    flow_control_ack: watch::Sender<Option<bool>>,
     /// Session history buffer for multi-session support (Phase 04)
     /// Stores SessionHistory messages for inactive sessions
     session_history_buffer: Arc<Mutex<Vec<NetworkMessage>>>,
@@ -195,16 +228,28 @@ impl QuicClient {
         let endpoint = Endpoint::client("0.0.0.0:0".parse().unwrap())
             .expect("Failed to create QUIC client endpoint");
 
+        let (file_chunk_tx, file_chunk_rx) = mpsc::channel(FILE_CHUNK_QUEUE);
+
         Self {
             endpoint,
             connection: None,
             server_fingerprint,
             send_stream: None,
             recv_task: None,
+            file_chunk_task: None,
             event_buffer: Arc::new(Mutex::new(Vec::new())),
             dir_chunk_buffer: Arc::new(Mutex::new(Vec::new())),
             file_event_buffer: Arc::new(Mutex::new(Vec::new())),
             file_content_buffer: Arc::new(Mutex::new(Vec::new())),
+            file_chunk_tx,
+            file_chunk_rx: Arc::new(Mutex::new(file_chunk_rx)),
+            file_read_request: Arc::new(AtomicU32::new(0)),
+            file_read_error: Arc::new(Mutex::new(None)),
+            file_read_progress: Arc::new(Mutex::new((0, 0))),
+            write_result_buffer: Arc::new(Mutex::new(Vec::new())),
+            shell_list: Arc::new(Mutex::new(None)),
+            completion_result: Arc::new(Mutex::new(None)),
+            flow_control_ack: watch::Sender::new(None),
             session_history_buffer: Arc::new(Mutex::new(Vec::new())),
             active_session_id: Arc::new(Mutex::new(None)),
         }
@@ -311,6 +356,10 @@ impl QuicClient {
         let dir_chunk_buffer = self.dir_chunk_buffer.clone();
         let file_event_buffer = self.file_event_buffer.clone();
         let file_content_buffer = self.file_content_buffer.clone();
+        let write_result_buffer = self.write_result_buffer.clone();
+        let shell_list = self.shell_list.clone();
+        let completion_result = self.completion_result.clone();
+        let flow_control_ack = self.flow_control_ack.clone();
         let session_history_buffer = self.session_history_buffer.clone();
         let active_session_id = self.active_session_id.clone();
         let recv_task = tokio::spawn(async move {
@@ -321,7 +370,6 @@ impl QuicClient {
             let mut recv_buffer = BytesMut::with_capacity(8192);
             let mut decode_failures = 0u32;
             const MAX_DECODE_FAILURES: u32 = 10;
-            const MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
 
             loop {
                 // Ensure capacity for next read
@@ -421,6 +469,27 @@ impl QuicClient {
                                         warn!("📥 [RECV_TASK] FileContent buffer full");
                                     }
                                 }
+                                NetworkMessage::WriteResult { .. } => {
+                                    let mut buffer = write_result_buffer.lock().await;
+                                    if buffer.len() < 100 {
+                                        buffer.push(msg);
+                                    } else {
+                                        warn!("📥 [RECV_TASK] WriteResult buffer full");
+                                    }
+                                }
+                                NetworkMessage::ShellList { shells } => {
+                                    info!("📥 [RECV_TASK] Received ShellList with {} shells", shells.len());
+                                    *shell_list.lock().await = Some(shells);
+                                }
+                                NetworkMessage::CompleteResult { suggestions } => {
+                                    debug!("📥 [RECV_TASK] Received CompleteResult with {} suggestions", suggestions.len());
+                                    // Latest wins: older results are stale once the user keeps typing
+                                    *completion_result.lock().await = Some(suggestions);
+                                }
+                                NetworkMessage::FlowControl { paused } => {
+                                    debug!("📥 [RECV_TASK] FlowControl acknowledged: paused={}", paused);
+                                    flow_control_ack.send_replace(Some(paused));
+                                }
                                 NetworkMessage::SessionHistory { .. } => {
                                     let mut buffer = session_history_buffer.lock().await;
                                     if buffer.len() < 100 {
@@ -459,10 +528,91 @@ impl QuicClient {
         });
 
         self.recv_task = Some(recv_task);
+
+        // Step 9: Accept FileChunk streams; the server opens one per download
+        let file_chunk_connection = connection.clone();
+        let file_chunk_tx = self.file_chunk_tx.clone();
+        let file_read_request = self.file_read_request.clone();
+        let file_read_error = self.file_read_error.clone();
+        let file_chunk_task = tokio::spawn(async move {
+            while let Ok(recv) = file_chunk_connection.accept_uni().await {
+                tokio::spawn(Self::read_file_chunk_stream(
+                    recv,
+                    file_chunk_tx.clone(),
+                    file_read_request.clone(),
+                    file_read_error.clone(),
+                ));
+            }
+        });
+
+        self.file_chunk_task = Some(file_chunk_task);
         self.connection = Some(connection);
         Ok(())
     }
 
+    /// Read one download's FileChunk stream into the chunk queue
+    ///
+    /// Waits while the queue is full, which only slows this download. Stops
+    /// as soon as the download is no longer the current request; dropping
+    /// the stream tells the server to stop sending.
+    async fn read_file_chunk_stream(
+        mut recv: RecvStream,
+        file_chunk_tx: mpsc::Sender<NetworkMessage>,
+        file_read_request: Arc<AtomicU32>,
+        file_read_error: Arc<Mutex<Option<String>>>,
+    ) {
+        // request_id of this stream, known from its first chunk
+        let mut request_id = None;
+
+        let failure = loop {
+            let mut len_buf = [0u8; 4];
+            match recv.read_exact(&mut len_buf).await {
+                Ok(()) => {}
+                Err(quinn::ReadExactError::FinishedEarly(0)) => return, // Download complete
+                Err(e) => break format!("File download failed: {}", e),
+            }
+
+            let len = u32::from_be_bytes(len_buf) as usize;
+            if len > MAX_MESSAGE_SIZE {
+                break format!("File download failed: message too large ({} bytes)", len);
+            }
+
+            // MessageCodec::decode expects the buffer WITH length prefix
+            let mut buf = vec![0u8; 4 + len];
+            buf[..4].copy_from_slice(&len_buf);
+            if let Err(e) = recv.read_exact(&mut buf[4..]).await {
+                break format!("File download failed: {}", e);
+            }
+
+            let msg = match MessageCodec::decode(&buf) {
+                Ok(msg @ NetworkMessage::FileChunk { .. }) => msg,
+                Ok(_) => {
+                    warn!("📥 [FILE_CHUNK] Unexpected message on FileChunk stream");
+                    continue;
+                }
+                Err(e) => break format!("File download failed: {}", e),
+            };
+
+            if let NetworkMessage::FileChunk { request_id: id, .. } = msg {
+                request_id = Some(id);
+            }
+            if request_id != Some(file_read_request.load(Ordering::SeqCst)) {
+                debug!("📥 [FILE_CHUNK] Dropping stream of replaced download {:?}", request_id);
+                return;
+            }
+
+            if file_chunk_tx.send(msg).await.is_err() {
+                return;
+            }
+        };
+
+        // Only report failures of the download the app is waiting for
+        if request_id == Some(file_read_request.load(Ordering::SeqCst)) {
+            error!("❌ [FILE_CHUNK] {}", failure);
+            *file_read_error.lock().await = Some(failure);
+        }
+    }
+
     /// Receive next terminal event from server (NON-BLOCKING)
     ///
     /// Phase 09: Polls from event buffer populated by background task.
@@ -550,6 +700,62 @@ impl QuicClient {
         Ok(())
     }
 
+    // ===== App Lifecycle =====
+
+    /// Prepare for app suspension (iOS/Android background)
+    ///
+    /// Asks server to pause output so it holds PTY data instead of sending it
+    /// to a suspended process. `write_all` only queues the message in quinn's
+    /// send buffer, so this waits until the host echoes the pause back; only
+    /// then is it safe to let the OS suspend the app.
+    ///
+    /// # Errors
+    /// Returns an error if the host does not acknowledge within
+    /// FLOW_CONTROL_ACK_TIMEOUT.
+    pub async fn enter_background(&self) -> Result<(), String> {
+        info!("🌙 [QUIC_CLIENT] enter_background");
+        // Forget older acks so only the echo of this pause counts
+        self.flow_control_ack.send_replace(None);
+        let mut ack = self.flow_control_ack.subscribe();
+
+        self.send_flow_control(NetworkMessage::pause_output()).await?;
+
+        let acked = tokio::time::timeout(FLOW_CONTROL_ACK_TIMEOUT, ack.wait_for(|paused| *paused == Some(true)))
+            .await
+            .is_ok();
+        if !acked {
+            return Err(format!(
+                "Host did not acknowledge pause within {}ms",
+                FLOW_CONTROL_ACK_TIMEOUT.as_millis()
+            ));
+        }
+
+        debug!("Host acknowledged pause");
+        Ok(())
+    }
+
+    /// Resume output after app returns to foreground
+    pub async fn enter_foreground(&self) -> Result<(), String> {
+        info!("☀️ [QUIC_CLIENT] enter_foreground");
+        self.send_flow_control(NetworkMessage::resume_output()).await
+    }
+
+    /// Send FlowControl message to server
+    async fn send_flow_control(&self, msg: NetworkMessage) -> Result<(), String> {
+        let send_stream = self.send_stream.as_ref()
+            .ok_or_else(|| "Not connected".to_string())?;
+
+        let encoded = MessageCodec::encode(&msg)
+            .map_err(|e| format!("Failed to encode FlowControl: {}", e))?;
+
+        let mut send = send_stream.lock().await;
+        send.write_all(&encoded).await
+            .map_err(|e| format!("Failed to send FlowControl: {}", e))?;
+
+        debug!("Sent FlowControl via QUIC");
+        Ok(())
+    }
+
     // ===== VFS Methods - Phase 1 =====
 
     /// Request directory listing from server
@@ -617,6 +823,9 @@ impl QuicClient {
             task.abort();
             info!("🛑 [QUIC_CLIENT] Background receive task aborted");
         }
+        if let Some(task) = self.file_chunk_task.take() {
+            task.abort();
+        }
 
         if let Some(conn) = &self.connection {
             conn.close(0u32.into(), b"Client disconnect");
@@ -633,6 +842,10 @@ impl QuicClient {
         file_buffer.clear();
         let mut file_content_buffer = self.file_content_buffer.lock().await;
         file_content_buffer.clear();
+        let mut file_chunk_rx = self.file_chunk_rx.lock().await;
+        while file_chunk_rx.try_recv().is_ok() {}
+        let mut write_result_buffer = self.write_result_buffer.lock().await;
+        write_result_buffer.clear();
 
         Ok(())
     }
@@ -786,6 +999,202 @@ impl QuicClient {
         self.file_content_buffer.lock().await.len()
     }
 
+    /// Request server to read a file in chunks (for large files)
+    ///
+    /// Server responds with FileChunk messages on a stream of their own; the
+    /// first one carries the total size. Call receive_file_chunk() until
+    /// has_more == false. Replaces any download still in progress.
+    pub async fn request_read_file_chunked(&self, path: String, chunk_size: u32) -> Result<(), String> {
+        info!("📄 [QUIC_CLIENT] request_read_file_chunked: {} (chunk_size: {})", path, chunk_size);
+
+        let send_stream = self.send_stream.as_ref()
+            .ok_or_else(|| "Not connected".to_string())?;
+
+        // Reset state for the new download; chunks of the old one still in
+        // flight are dropped by request_id
+        let request_id = self.file_read_request.fetch_add(1, Ordering::SeqCst).wrapping_add(1);
+        *self.file_read_progress.lock().await = (0, 0);
+        *self.file_read_error.lock().await = None;
+        let mut file_chunk_rx = self.file_chunk_rx.lock().await;
+        while file_chunk_rx.try_recv().is_ok() {}
+        drop(file_chunk_rx);
+
+        let msg = NetworkMessage::read_file_chunked(path, chunk_size, request_id);
+        let encoded = MessageCodec::encode(&msg)
+            .map_err(|e| format!("Failed to encode ReadFileChunked: {}", e))?;
+
+        let mut send = send_stream.lock().await;
+        send.write_all(&encoded).await
+            .map_err(|e| format!("Failed to send ReadFileChunked: {}", e))?;
+
+        info!("✅ [QUIC_CLIENT] ReadFileChunked request sent");
+        Ok(())
+    }
+
+    /// Receive next file chunk from server (NON-BLOCKING)
+    ///
+    /// Returns (path, chunk_index, data, has_more) tuple and updates
+    /// read progress (see file_read_progress()).
+    /// Returns None if no chunk available yet.
+    ///
+    /// # Errors
+    /// Returns the failure if the download stream broke off; no data is
+    /// silently lost.
+    pub async fn receive_file_chunk(&self) -> Result<Option<(String, u32, Vec<u8>, bool)>, String> {
+        if let Some(err) = self.file_read_error.lock().await.clone() {
+            return Err(err);
+        }
+
+        let current = self.file_read_request.load(Ordering::SeqCst);
+        let mut file_chunk_rx = self.file_chunk_rx.lock().await;
+        let msg = loop {
+            match file_chunk_rx.try_recv() {
+                // Queued before the previous download was replaced
+                Ok(NetworkMessage::FileChunk { request_id, .. }) if request_id != current => continue,
+                Ok(msg) => break msg,
+                Err(_) => return Ok(None),
+            }
+        };
+        drop(file_chunk_rx);
+
+        match msg {
+            NetworkMessage::FileChunk { path, chunk_index, data, total_size, has_more, .. } => {
+                let mut progress = self.file_read_progress.lock().await;
+                if let Some(total) = total_size {
+                    *progress = (0, total);
+                }
+                progress.0 += data.len() as u64;
+                info!("📥 [QUIC_CLIENT] Received FileChunk {} ({}/{} bytes), has_more={}",
+                    chunk_index, progress.0, progress.1, has_more);
+                Ok(Some((path, chunk_index, data, has_more)))
+            }
+            _ => unreachable!(), // Only FileChunk is queued here
+        }
+    }
+
+    /// Get chunked read progress as (received bytes, total size)
+    ///
+    /// Total is 0 until the first chunk arrives.
+    pub async fn file_read_progress(&self) -> (u64, u64) {
+        *self.file_read_progress.lock().await
+    }
+
+    /// Request list of shells installed on the host
+    ///
+    /// Server responds with ShellList message.
+    /// Call receive_shell_list() to get the result.
+    pub async fn request_shells(&self) -> Result<(), String> {
+        let send_stream = self.send_stream.as_ref()
+            .ok_or_else(|| "Not connected".to_string())?;
+
+        let encoded = MessageCodec::encode(&NetworkMessage::QueryShells)
+            .map_err(|e| format!("Failed to encode QueryShells: {}", e))?;
+
+        let mut send = send_stream.lock().await;
+        send.write_all(&encoded).await
+            .map_err(|e| format!("Failed to send QueryShells: {}", e))?;
+
+        info!("✅ [QUIC_CLIENT] QueryShells request sent");
+        Ok(())
+    }
+
+    /// Take the latest shell list from server (NON-BLOCKING)
+    ///
+    /// Returns None if no ShellList received since the last call.
+    pub async fn receive_shell_list(&self) -> Option<Vec<String>> {
+        self.shell_list.lock().await.take()
+    }
+
+    // ===== VFS File Writing Methods =====
+
+    /// Request server to write a whole file (atomic on the host)
+    ///
+    /// Server responds with WriteResult message.
+    /// Call receive_write_result() to receive the outcome.
+    pub async fn request_write_file(&self, path: String, data: Vec<u8>) -> Result<(), String> {
+        info!("📝 [QUIC_CLIENT] request_write_file: {} ({} bytes)", path, data.len());
+        self.send_write_message(NetworkMessage::WriteFile { path, data }).await
+    }
+
+    /// Request server to write data at offset (chunked upload)
+    ///
+    /// Server responds with one WriteResult message per chunk.
+    pub async fn request_write_chunk(&self, path: String, offset: u64, data: Vec<u8>) -> Result<(), String> {
+        info!("📝 [QUIC_CLIENT] request_write_chunk: {} (offset: {}, {} bytes)", path, offset, data.len());
+        self.send_write_message(NetworkMessage::WriteChunk { path, offset, data }).await
+    }
+
+    async fn send_write_message(&self, msg: NetworkMessage) -> Result<(), String> {
+        let send_stream = self.send_stream.as_ref()
+            .ok_or_else(|| "Not connected".to_string())?;
+
+        let encoded = MessageCodec::encode(&msg)
+            .map_err(|e| format!("Failed to encode write request: {}", e))?;
+
+        let mut send = send_stream.lock().await;
+        send.write_all(&encoded).await
+            .map_err(|e| format!("Failed to send write request: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Receive write result from server (NON-BLOCKING)
+    ///
+    /// Returns (path, bytes_written, error) tuple; error is None on success.
+    /// Returns None if no result available yet.
+    pub async fn receive_write_result(&self) -> Result<Option<(String, u64, Option<WriteError>)>, String> {
+        let mut buffer = self.write_result_buffer.lock().await;
+
+        if buffer.is_empty() {
+            return Ok(None);
+        }
+
+        match buffer.remove(0) {
+            NetworkMessage::WriteResult { path, bytes_written, error } => {
+                info!("📥 [QUIC_CLIENT] Received WriteResult: {} bytes, error={:?}", bytes_written, error);
+                Ok(Some((path, bytes_written, error)))
+            }
+            _ => unreachable!(), // Only WriteResult is buffered here
+        }
+    }
+
+    // ===== Command Completion =====
+
+    /// Request path completion for the token under the cursor
+    ///
+    /// Server responds with CompleteResult message.
+    /// Call receive_completion() to get the suggestions.
+    ///
+    /// # Arguments
+    /// * `session_id` - Session whose project directory is used as base
+    /// * `line` - Current command line
+    /// * `cursor` - Cursor position in characters
+    pub async fn request_completion(&self, session_id: String, line: String, cursor: usize) -> Result<(), String> {
+        let send_stream = self.send_stream.as_ref()
+            .ok_or_else(|| "Not connected".to_string())?;
+
+        // Drop any stale result from a previous request
+        *self.completion_result.lock().await = None;
+
+        let msg = NetworkMessage::complete_request(session_id, line, cursor);
+        let encoded = MessageCodec::encode(&msg)
+            .map_err(|e| format!("Failed to encode CompleteRequest: {}", e))?;
+
+        let mut send = send_stream.lock().await;
+        send.write_all(&encoded).await
+            .map_err(|e| format!("Failed to send CompleteRequest: {}", e))?;
+
+        debug!("✅ [QUIC_CLIENT] CompleteRequest sent (cursor: {})", cursor);
+        Ok(())
+    }
+
+    /// Take the latest completion suggestions (NON-BLOCKING)
+    ///
+    /// Returns None if no CompleteResult received since the last call.
+    pub async fn receive_completion(&self) -> Option<Vec<String>> {
+        self.completion_result.lock().await.take()
+    }
+
     // ===== Multi-Session Management - Phase 04 =====
 
     /// Create a new PTY session with UUID
@@ -795,13 +1204,21 @@ impl QuicClient {
     /// # Arguments
     /// * `project_path` - Absolute path to project directory
     /// * `session_id` - UUID string for the session (from Flutter)
-    pub async fn create_session(&self, project_path: String, session_id: String) -> Result<(), String> {
-        info!("📝 [QUIC_CLIENT] create_session: {} at {}", session_id, project_path);
+    /// * `locale` - Optional locale for LANG/LC_ALL (None = host default)
+    /// * `shell` - Optional shell path from the host's ShellList (None = host default)
+    pub async fn create_session(
+        &self,
+        project_path: String,
+        session_id: String,
+        locale: Option<String>,
+        shell: Option<String>,
+    ) -> Result<(), String> {
+        info!("📝 [QUIC_CLIENT] create_session: {} at {} (locale: {:?}, shell: {:?})", session_id, project_path, locale, shell);
 
         let send_stream = self.send_stream.as_ref()
             .ok_or_else(|| "Not connected".to_string())?;
 
-        let session_msg = SessionMessage::CreateSession { project_path, session_id };
+        let session_msg = SessionMessage::CreateSession { project_path, session_id, locale, shell };
         let msg = NetworkMessage::Session(session_msg);
         let encoded = MessageCodec::encode(&msg)
             .map_err(|e| format!("Failed to encode CreateSession: {}", e))?;
@@ -1061,6 +1478,206 @@ mod tests {
         assert!(result.unwrap_err().contains("Port cannot be 0"));
     }
 
+    #[tokio::test]
+    async fn test_enter_background_not_connected() {
+        let client = QuicClient::new("AA:BB:CC".to_string());
+        assert!(client.enter_background().await.is_err());
+        assert!(client.enter_foreground().await.is_err());
+    }
+
+    /// Spawn loopback server that answers Hello and forwards other messages
+    ///
+    /// FlowControl is echoed back after `flow_ack_delay` like the host does,
+    /// or never if None. ReadFileChunked is answered with LOOPBACK_CHUNKS
+    /// one-byte chunks on a new uni stream, then a "ready" Output event on
+    /// the main stream.
+    /// Returns (port, certificate fingerprint, received messages).
+    fn spawn_loopback_server(
+        flow_ack_delay: Option<Duration>,
+    ) -> (u16, String, tokio::sync::mpsc::UnboundedReceiver<NetworkMessage>) {
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let cert = rcgen::generate_simple_self_signed(vec!["comacode-host".to_string()]).unwrap();
+        let cert_der = CertificateDer::from(cert.cert.der().to_vec());
+        let key_der = rustls_pki_types::PrivateKeyDer::Pkcs8(cert.key_pair.serialize_der().into());
+        let fingerprint = TofuVerifier::new(String::new()).calculate_fingerprint(&cert_der);
+
+        let server_config = comacode_core::transport::configure_server(vec![cert_der], key_der).unwrap();
+        let endpoint = Endpoint::server(server_config, "127.0.0.1:0".parse().unwrap()).unwrap();
+        let port = endpoint.local_addr().unwrap().port();
+
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let connection = endpoint.accept().await.unwrap().await.unwrap();
+            let (mut send, mut recv) = connection.accept_bi().await.unwrap();
+
+            let mut buf = BytesMut::with_capacity(8192);
+            let mut read_buf = [0u8; 1024];
+            while let Ok(Some(n)) = recv.read(&mut read_buf).await {
+                buf.extend_from_slice(&read_buf[..n]);
+                while buf.len() >= 4 {
+                    let len = u32::from_be_bytes([buf[0], buf[1], buf[2], buf[3]]) as usize;
+                    if buf.len() < 4 + len {
+                        break;
+                    }
+                    let msg = MessageCodec::decode(&buf[..4 + len]).unwrap();
+                    buf.advance(4 + len);
+
+                    match msg {
+                        NetworkMessage::Hello { .. } => {
+                            let ack = MessageCodec::encode(&NetworkMessage::hello(None)).unwrap();
+                            send.write_all(&ack).await.unwrap();
+                        }
+                        NetworkMessage::ReadFileChunked { path, request_id, .. } => {
+                            let mut chunks = connection.open_uni().await.unwrap();
+                            for i in 0..LOOPBACK_CHUNKS {
+                                let chunk = NetworkMessage::FileChunk {
+                                    path: path.clone(),
+                                    chunk_index: i,
+                                    offset: i as u64,
+                                    data: vec![i as u8],
+                                    total_size: (i == 0).then_some(LOOPBACK_CHUNKS as u64),
+                                    has_more: i + 1 < LOOPBACK_CHUNKS,
+                                    request_id,
+                                };
+                                // Fails once the client drops a replaced download
+                                let _ = chunks.write_all(&MessageCodec::encode(&chunk).unwrap()).await;
+                            }
+                            let _ = chunks.finish();
+
+                            let ready = NetworkMessage::Event(TerminalEvent::output_str("ready"));
+                            send.write_all(&MessageCodec::encode(&ready).unwrap()).await.unwrap();
+                        }
+                        NetworkMessage::FlowControl { .. } => {
+                            let _ = tx.send(msg.clone());
+                            if let Some(delay) = flow_ack_delay {
+                                tokio::time::sleep(delay).await;
+                                send.write_all(&MessageCodec::encode(&msg).unwrap()).await.unwrap();
+                            }
+                        }
+                        _ => {
+                            let _ = tx.send(msg);
+                        }
+                    }
+                }
+            }
+        });
+
+        (port, fingerprint, rx)
+    }
+
+    #[tokio::test]
+    async fn test_enter_background_pauses_and_foreground_resumes() {
+        let ack_delay = Duration::from_millis(300);
+        let (port, fingerprint, mut received) = spawn_loopback_server(Some(ack_delay));
+
+        let mut client = QuicClient::new(fingerprint);
+        client.connect("127.0.0.1".to_string(), port, AuthToken::generate().to_hex()).await.unwrap();
+
+        let timeout = std::time::Duration::from_secs(5);
+
+        // Must not return before the host has echoed the pause
+        let started = std::time::Instant::now();
+        client.enter_background().await.unwrap();
+        assert!(started.elapsed() >= ack_delay);
+        let msg = tokio::time::timeout(timeout, received.recv()).await.unwrap();
+        assert_eq!(msg, Some(NetworkMessage::FlowControl { paused: true }));
+
+        client.enter_foreground().await.unwrap();
+        let msg = tokio::time::timeout(timeout, received.recv()).await.unwrap();
+        assert_eq!(msg, Some(NetworkMessage::FlowControl { paused: false }));
+
+        client.disconnect().await.unwrap();
+    }
+
+    /// Chunks per download sent by the loopback server (more than the queue holds)
+    const LOOPBACK_CHUNKS: u32 = FILE_CHUNK_QUEUE as u32 * 2;
+
+    /// Poll receive_event() until the loopback server's "ready" event arrives
+    async fn wait_for_ready(client: &QuicClient) {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                match client.receive_event().await.unwrap() {
+                    TerminalEvent::Output { data } if data == b"ready" => break,
+                    _ => tokio::time::sleep(Duration::from_millis(10)).await,
+                }
+            }
+        })
+        .await
+        .expect("terminal output blocked");
+    }
+
+    /// Poll receive_file_chunk() for the next chunk
+    async fn next_file_chunk(client: &QuicClient) -> (String, u32, Vec<u8>, bool) {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                if let Some(chunk) = client.receive_file_chunk().await.unwrap() {
+                    break chunk;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("no file chunk")
+    }
+
+    #[tokio::test]
+    async fn test_chunked_download_does_not_block_terminal() {
+        let (port, fingerprint, _received) = spawn_loopback_server(None);
+
+        let mut client = QuicClient::new(fingerprint);
+        client.connect("127.0.0.1".to_string(), port, AuthToken::generate().to_hex()).await.unwrap();
+
+        // More chunks than the queue holds, none consumed yet
+        client.request_read_file_chunked("a".to_string(), 4096).await.unwrap();
+        wait_for_ready(&client).await;
+
+        for i in 0..LOOPBACK_CHUNKS {
+            let (path, chunk_index, data, has_more) = next_file_chunk(&client).await;
+            assert_eq!((path.as_str(), chunk_index, data, has_more), ("a", i, vec![i as u8], i + 1 < LOOPBACK_CHUNKS));
+        }
+        assert_eq!(client.file_read_progress().await, (LOOPBACK_CHUNKS as u64, LOOPBACK_CHUNKS as u64));
+
+        client.disconnect().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_new_download_drops_chunks_of_replaced_one() {
+        let (port, fingerprint, _received) = spawn_loopback_server(None);
+
+        let mut client = QuicClient::new(fingerprint);
+        client.connect("127.0.0.1".to_string(), port, AuthToken::generate().to_hex()).await.unwrap();
+
+        // First download fills the queue and is then replaced
+        client.request_read_file_chunked("a".to_string(), 4096).await.unwrap();
+        wait_for_ready(&client).await;
+        client.request_read_file_chunked("b".to_string(), 4096).await.unwrap();
+        wait_for_ready(&client).await;
+
+        for i in 0..LOOPBACK_CHUNKS {
+            let (path, chunk_index, _, _) = next_file_chunk(&client).await;
+            assert_eq!((path.as_str(), chunk_index), ("b", i));
+        }
+        assert!(client.receive_file_chunk().await.unwrap().is_none());
+
+        client.disconnect().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_enter_background_fails_without_ack() {
+        let (port, fingerprint, mut received) = spawn_loopback_server(None);
+
+        let mut client = QuicClient::new(fingerprint);
+        client.connect("127.0.0.1".to_string(), port, AuthToken::generate().to_hex()).await.unwrap();
+
+        let err = client.enter_background().await.unwrap_err();
+        assert!(err.contains("did not acknowledge"), "{}", err);
+        let msg = tokio::time::timeout(std::time::Duration::from_secs(5), received.recv()).await.unwrap();
+        assert_eq!(msg, Some(NetworkMessage::FlowControl { paused: true }));
+
+        client.disconnect().await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_quic_client_invalid_token() {
         let mut client = QuicClient::new("AA:BB:CC".to_string());
@@ -81,6 +81,28 @@ Future<void> sendRawInput({required List<int> data}) =>
 Future<void> resizePty({required int rows, required int cols}) =>
     RustLib.instance.api.crateApiResizePty(rows: rows, cols: cols);
 
+/// Notify server that the app is going to background
+///
+/// Call from the app lifecycle handler (paused/inactive) before the OS
+/// suspends the process. Server holds terminal output until
+/// `enter_foreground` is called. Returns once the server has acknowledged
+/// the pause.
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized, or an error if the
+/// server does not acknowledge the pause within a second.
+Future<void> enterBackground() =>
+    RustLib.instance.api.crateApiEnterBackground();
+
+/// Notify server that the app is back in foreground
+///
+/// Resumes terminal output paused by `enter_background`.
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+Future<void> enterForeground() =>
+    RustLib.instance.api.crateApiEnterForeground();
+
 /// Disconnect from host
 ///
 /// Clears the client, allowing reconnect.
@@ -177,6 +199,12 @@ String getEventErrorMessage({required TerminalEvent event}) =>
 int getEventExitCode({required TerminalEvent event}) =>
     RustLib.instance.api.crateApiGetEventExitCode(event: event);
 
+/// Get expected output charset (for SessionCreated events)
+///
+/// Returns "UTF-8" for other events.
+String getEventCharset({required TerminalEvent event}) =>
+    RustLib.instance.api.crateApiGetEventCharset(event: event);
+
 /// Check if event is Output
 bool isEventOutput({required TerminalEvent event}) =>
     RustLib.instance.api.crateApiIsEventOutput(event: event);
@@ -346,6 +374,119 @@ Future<FileContentData?> receiveFileContent() =>
 Future<BigInt> fileContentBufferLen() =>
     RustLib.instance.api.crateApiFileContentBufferLen();
 
+/// Request server to read a large file in chunks
+///
+/// Server responds with file chunks. Call receive_file_chunk() in a loop
+/// until `has_more` is false. A new request replaces any download still in
+/// progress; its remaining chunks are never returned.
+///
+/// # Arguments
+/// * `path` - Absolute path to file
+/// * `chunk_size` - Bytes per chunk (server clamps to 4KB..1MB, default: 65536)
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+Future<void> requestReadFileChunked({
+  required String path,
+  required int chunkSize,
+}) => RustLib.instance.api.crateApiRequestReadFileChunked(
+  path: path,
+  chunkSize: chunkSize,
+);
+
+/// Receive next file chunk from server (NON-BLOCKING)
+///
+/// Progress for a download is `received_bytes / total_size`.
+///
+/// # Returns
+/// * `Some(FileChunkData)` - Chunk received, with cumulative progress
+/// * `None` - No chunk available yet
+///
+/// Only a few chunks are queued; the download waits until they are taken,
+/// without holding up terminal output.
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized, or the failure of a
+/// download that broke off.
+Future<FileChunkData?> receiveFileChunk() =>
+    RustLib.instance.api.crateApiReceiveFileChunk();
+
+/// Request server to write a whole file
+///
+/// Host writes to a temp file and renames it, so the target is untouched on
+/// failure. Call receive_write_result() to get the outcome.
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+Future<void> requestWriteFile({
+  required String path,
+  required List<int> data,
+}) => RustLib.instance.api.crateApiRequestWriteFile(path: path, data: data);
+
+/// Request server to write data at offset (chunked upload)
+///
+/// Server sends one write result per chunk. On `write_partial`, resend the
+/// rest starting at `offset + bytes_written`.
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+Future<void> requestWriteChunk({
+  required String path,
+  required BigInt offset,
+  required List<int> data,
+}) => RustLib.instance.api.crateApiRequestWriteChunk(
+  path: path,
+  offset: offset,
+  data: data,
+);
+
+/// Receive next write result from server (NON-BLOCKING)
+///
+/// # Returns
+/// * `Some(WriteResultData)` - Write result received
+/// * `None` - No result available yet
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+Future<WriteResultData?> receiveWriteResult() =>
+    RustLib.instance.api.crateApiReceiveWriteResult();
+
+/// Request path completion for the token under the cursor (Tab key)
+///
+/// Server completes against the session's project directory.
+/// Call receive_completion() to get the suggestions.
+///
+/// # Arguments
+/// * `session_id` - Active session UUID
+/// * `line` - Current command line
+/// * `cursor` - Cursor position in characters
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+Future<void> requestCompletion({
+  required String sessionId,
+  required String line,
+  required BigInt cursor,
+}) => RustLib.instance.api.crateApiRequestCompletion(
+  sessionId: sessionId,
+  line: line,
+  cursor: cursor,
+);
+
+/// Receive completion suggestions (NON-BLOCKING)
+///
+/// Each suggestion replaces the whole token under the cursor.
+/// Directories end with `/`.
+///
+/// # Returns
+/// * `Some(suggestions)` - Suggestions received (may be empty)
+/// * `None` - No response yet
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+Future<List<String>?> receiveCompletion() =>
+    RustLib.instance.api.crateApiReceiveCompletion();
+
 /// Create a new PTY session with UUID
 ///
 /// Sends CreateSession message to server. Server creates PTY in project directory.
@@ -364,6 +505,52 @@ Future<void> createSession({
   sessionId: sessionId,
 );
 
+/// Create a new PTY session with explicit locale and/or shell
+///
+/// Same as `create_session`, but sets LANG/LC_ALL and the shell on the host
+/// PTY. Server reports the resulting charset in the SessionCreated event
+/// (see `get_event_charset`).
+///
+/// # Arguments
+/// * `project_path` - Absolute path to project directory
+/// * `session_id` - UUID string for the session
+/// * `locale` - Locale name, e.g. "vi_VN.UTF-8" (charset defaults to UTF-8)
+/// * `shell` - Shell path from `receive_shell_list()`; the host rejects others
+///   with an Error event and creates no session
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+Future<void> createSessionWithOptions({
+  required String projectPath,
+  required String sessionId,
+  String? locale,
+  String? shell,
+}) => RustLib.instance.api.crateApiCreateSessionWithOptions(
+  projectPath: projectPath,
+  sessionId: sessionId,
+  locale: locale,
+  shell: shell,
+);
+
+/// Request list of shells installed on the host
+///
+/// Server responds with the shell list. Call receive_shell_list() to get it.
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+Future<void> requestShells() => RustLib.instance.api.crateApiRequestShells();
+
+/// Receive host shell list (NON-BLOCKING)
+///
+/// # Returns
+/// * `Some(shells)` - Shell paths, usable as `shell` in `create_session_with_options`
+/// * `None` - No response yet
+///
+/// # Errors
+/// Returns "Not connected" if client not initialized.
+Future<List<String>?> receiveShellList() =>
+    RustLib.instance.api.crateApiReceiveShellList();
+
 /// Check if session exists on server (for re-attach on app restart)
 ///
 /// Sends CheckSession message. Server responds with SessionReAttach or SessionNotFound event.
@@ -467,6 +654,57 @@ abstract class TerminalCommand implements RustOpaqueInterface {}
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TerminalEvent>>
 abstract class TerminalEvent implements RustOpaqueInterface {}
 
+/// File chunk data with download progress (for Dart)
+class FileChunkData {
+  /// File path
+  final String path;
+
+  /// Chunk index (0-based)
+  final int chunkIndex;
+
+  /// Raw chunk bytes
+  final Uint8List data;
+
+  /// True if more chunks follow
+  final bool hasMore;
+
+  /// Total bytes received so far
+  final BigInt receivedBytes;
+
+  /// File size on disk (from first chunk)
+  final BigInt totalSize;
+
+  const FileChunkData({
+    required this.path,
+    required this.chunkIndex,
+    required this.data,
+    required this.hasMore,
+    required this.receivedBytes,
+    required this.totalSize,
+  });
+
+  @override
+  int get hashCode =>
+      path.hashCode ^
+      chunkIndex.hashCode ^
+      data.hashCode ^
+      hasMore.hashCode ^
+      receivedBytes.hashCode ^
+      totalSize.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FileChunkData &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          chunkIndex == other.chunkIndex &&
+          data == other.data &&
+          hasMore == other.hasMore &&
+          receivedBytes == other.receivedBytes &&
+          totalSize == other.totalSize;
+}
+
 /// File content data (for Dart)
 class FileContentData {
   /// File path
@@ -685,3 +923,42 @@ sealed class VibeInput with _$VibeInput {
   /// Raw bytes
   const factory VibeInput.raw({required Uint8List data}) = VibeInput_Raw;
 }
+
+/// Write result data (for Dart)
+class WriteResultData {
+  /// File path
+  final String path;
+
+  /// Bytes actually written (less than requested on failure)
+  final BigInt bytesWritten;
+
+  /// Error kind: "" (success), "disk_full", "write_partial", "permission_denied", or "io"
+  final String errorKind;
+
+  /// Human-readable error message (empty on success)
+  final String error;
+
+  const WriteResultData({
+    required this.path,
+    required this.bytesWritten,
+    required this.errorKind,
+    required this.error,
+  });
+
+  @override
+  int get hashCode =>
+      path.hashCode ^
+      bytesWritten.hashCode ^
+      errorKind.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WriteResultData &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          bytesWritten == other.bytesWritten &&
+          errorKind == other.errorKind &&
+          error == other.error;
+}
@@ -64,7 +64,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -96756891;
+  int get rustContentHash => 815290612;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -95,6 +95,13 @@ abstract class RustLibApi extends BaseApi {
     required String sessionId,
   });
 
+  Future<void> crateApiCreateSessionWithOptions({
+    required String projectPath,
+    required String sessionId,
+    String? locale,
+    String? shell,
+  });
+
   TerminalConfig crateApiCreateTerminalConfig({
     required int rows,
     required int cols,
@@ -115,6 +122,10 @@ abstract class RustLibApi extends BaseApi {
     required int cols,
   });
 
+  Future<void> crateApiEnterBackground();
+
+  Future<void> crateApiEnterForeground();
+
   TerminalEvent crateApiEventOutput({required List<int> data});
 
   TerminalEvent crateApiEventOutputStr({required String s});
@@ -145,6 +156,8 @@ abstract class RustLibApi extends BaseApi {
 
   BigInt? crateApiGetDirEntrySize({required DirEntry entry});
 
+  String crateApiGetEventCharset({required TerminalEvent event});
+
   Uint8List crateApiGetEventData({required TerminalEvent event});
 
   String crateApiGetEventErrorMessage({required TerminalEvent event});
@@ -181,16 +194,30 @@ abstract class RustLibApi extends BaseApi {
 
   Future<QrPayload> crateApiParseQrPayload({required String json});
 
+  Future<List<String>?> crateApiReceiveCompletion();
+
   Future<(int, List<DirEntry>, bool)?> crateApiReceiveDirChunk();
 
+  Future<FileChunkData?> crateApiReceiveFileChunk();
+
   Future<FileContentData?> crateApiReceiveFileContent();
 
   Future<FileWatcherEventData?> crateApiReceiveFileEvent();
 
   Future<SessionHistoryData?> crateApiReceiveSessionHistory();
 
+  Future<List<String>?> crateApiReceiveShellList();
+
   Future<TerminalEvent> crateApiReceiveTerminalEvent();
 
+  Future<WriteResultData?> crateApiReceiveWriteResult();
+
+  Future<void> crateApiRequestCompletion({
+    required String sessionId,
+    required String line,
+    required BigInt cursor,
+  });
+
   Future<void> crateApiRequestListDir({required String path});
 
   Future<void> crateApiRequestReadFile({
@@ -198,10 +225,28 @@ abstract class RustLibApi extends BaseApi {
     required BigInt maxSize,
   });
 
+  Future<void> crateApiRequestReadFileChunked({
+    required String path,
+    required int chunkSize,
+  });
+
+  Future<void> crateApiRequestShells();
+
   Future<void> crateApiRequestUnwatchDir({required String watcherId});
 
   Future<void> crateApiRequestWatchDir({required String path});
 
+  Future<void> crateApiRequestWriteChunk({
+    required String path,
+    required BigInt offset,
+    required List<int> data,
+  });
+
+  Future<void> crateApiRequestWriteFile({
+    required String path,
+    required List<int> data,
+  });
+
   Future<void> crateApiResizePty({required int rows, required int cols});
 
   Future<void> crateApiSendRawInput({required List<int> data});
@@ -442,6 +487,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ["projectPath", "sessionId"],
   );
 
+  @override
+  Future<void> crateApiCreateSessionWithOptions({
+    required String projectPath,
+    required String sessionId,
+    String? locale,
+    String? shell,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(projectPath, serializer);
+          sse_encode_String(sessionId, serializer);
+          sse_encode_opt_String(locale, serializer);
+          sse_encode_opt_String(shell, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 7,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCreateSessionWithOptionsConstMeta,
+        argValues: [projectPath, sessionId, locale, shell],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCreateSessionWithOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_session_with_options",
+        argNames: ["projectPath", "sessionId", "locale", "shell"],
+      );
+
   @override
   TerminalConfig crateApiCreateTerminalConfig({
     required int rows,
@@ -453,7 +537,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_u_16(rows, serializer);
           sse_encode_u_16(cols, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_terminal_config,
@@ -482,7 +566,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 8,
+            funcId: 9,
             port: port_,
           );
         },
@@ -509,7 +593,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 9,
+            funcId: 10,
             port: port_,
           );
         },
@@ -540,7 +624,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 10,
+            funcId: 11,
             port: port_,
           );
         },
@@ -568,7 +652,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 11,
+            funcId: 12,
             port: port_,
           );
         },
@@ -595,7 +679,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 12,
+            funcId: 13,
             port: port_,
           );
         },
@@ -627,7 +711,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 13,
+            funcId: 14,
             port: port_,
           );
         },
@@ -647,6 +731,60 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ["rows", "cols"],
   );
 
+  @override
+  Future<void> crateApiEnterBackground() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiEnterBackgroundConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEnterBackgroundConstMeta =>
+      const TaskConstMeta(debugName: "enter_background", argNames: []);
+
+  @override
+  Future<void> crateApiEnterForeground() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiEnterForegroundConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEnterForegroundConstMeta =>
+      const TaskConstMeta(debugName: "enter_foreground", argNames: []);
+
   @override
   TerminalEvent crateApiEventOutput({required List<int> data}) {
     return handler.executeSync(
@@ -654,7 +792,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
         },
         codec: SseCodec(
           decodeSuccessData:
@@ -678,7 +816,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(s, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
         },
         codec: SseCodec(
           decodeSuccessData:
@@ -704,7 +842,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 16,
+            funcId: 19,
             port: port_,
           );
         },
@@ -731,7 +869,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 17,
+            funcId: 20,
             port: port_,
           );
         },
@@ -758,7 +896,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 18,
+            funcId: 21,
             port: port_,
           );
         },
@@ -785,7 +923,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 19,
+            funcId: 22,
             port: port_,
           );
         },
@@ -815,7 +953,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 23,
             port: port_,
           );
         },
@@ -843,7 +981,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             cmd,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_u_64,
@@ -869,7 +1007,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             cmd,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -895,7 +1033,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             cmd,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_u_64,
@@ -924,7 +1062,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             entry,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_u_64,
@@ -953,7 +1091,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             entry,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -979,7 +1117,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             entry,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -1005,7 +1143,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             entry,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_opt_String,
@@ -1034,7 +1172,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             entry,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_u_64,
@@ -1050,6 +1188,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiGetDirEntrySizeConstMeta =>
       const TaskConstMeta(debugName: "get_dir_entry_size", argNames: ["entry"]);
 
+  @override
+  String crateApiGetEventCharset({required TerminalEvent event}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEvent(
+            event,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetEventCharsetConstMeta,
+        argValues: [event],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetEventCharsetConstMeta =>
+      const TaskConstMeta(debugName: "get_event_charset", argNames: ["event"]);
+
   @override
   Uint8List crateApiGetEventData({required TerminalEvent event}) {
     return handler.executeSync(
@@ -1060,7 +1224,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             event,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
@@ -1086,7 +1250,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             event,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -1115,7 +1279,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             event,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_i_32,
@@ -1143,7 +1307,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             payload,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -1171,7 +1335,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             payload,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -1197,7 +1361,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             payload,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_u_16,
@@ -1223,7 +1387,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             payload,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_u_32,
@@ -1252,7 +1416,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             payload,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -1275,7 +1439,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -1300,7 +1464,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 38,
+            funcId: 42,
             port: port_,
           );
         },
@@ -1328,7 +1492,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             entry,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
@@ -1354,7 +1518,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             entry,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
@@ -1382,7 +1546,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             event,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
@@ -1408,7 +1572,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             event,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
@@ -1434,7 +1598,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             event,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
@@ -1460,7 +1624,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 44,
+            funcId: 48,
             port: port_,
           );
         },
@@ -1488,7 +1652,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 45,
+            funcId: 49,
             port: port_,
           );
         },
@@ -1516,7 +1680,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 46,
+            funcId: 50,
             port: port_,
           );
         },
@@ -1535,6 +1699,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiParseQrPayloadConstMeta =>
       const TaskConstMeta(debugName: "parse_qr_payload", argNames: ["json"]);
 
+  @override
+  Future<List<String>?> crateApiReceiveCompletion() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_list_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiReceiveCompletionConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReceiveCompletionConstMeta =>
+      const TaskConstMeta(debugName: "receive_completion", argNames: []);
+
   @override
   Future<(int, List<DirEntry>, bool)?> crateApiReceiveDirChunk() {
     return handler.executeNormal(
@@ -1544,7 +1735,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 47,
+            funcId: 52,
             port: port_,
           );
         },
@@ -1563,6 +1754,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiReceiveDirChunkConstMeta =>
       const TaskConstMeta(debugName: "receive_dir_chunk", argNames: []);
 
+  @override
+  Future<FileChunkData?> crateApiReceiveFileChunk() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_file_chunk_data,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiReceiveFileChunkConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReceiveFileChunkConstMeta =>
+      const TaskConstMeta(debugName: "receive_file_chunk", argNames: []);
+
   @override
   Future<FileContentData?> crateApiReceiveFileContent() {
     return handler.executeNormal(
@@ -1572,7 +1790,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 48,
+            funcId: 54,
             port: port_,
           );
         },
@@ -1591,61 +1809,276 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "receive_file_content", argNames: []);
 
   @override
-  Future<FileWatcherEventData?> crateApiReceiveFileEvent() {
+  Future<FileWatcherEventData?> crateApiReceiveFileEvent() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_file_watcher_event_data,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiReceiveFileEventConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReceiveFileEventConstMeta =>
+      const TaskConstMeta(debugName: "receive_file_event", argNames: []);
+
+  @override
+  Future<SessionHistoryData?> crateApiReceiveSessionHistory() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_session_history_data,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiReceiveSessionHistoryConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReceiveSessionHistoryConstMeta =>
+      const TaskConstMeta(debugName: "receive_session_history", argNames: []);
+
+  @override
+  Future<List<String>?> crateApiReceiveShellList() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_list_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiReceiveShellListConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReceiveShellListConstMeta =>
+      const TaskConstMeta(debugName: "receive_shell_list", argNames: []);
+
+  @override
+  Future<TerminalEvent> crateApiReceiveTerminalEvent() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEvent,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiReceiveTerminalEventConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReceiveTerminalEventConstMeta =>
+      const TaskConstMeta(debugName: "receive_terminal_event", argNames: []);
+
+  @override
+  Future<WriteResultData?> crateApiReceiveWriteResult() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_write_result_data,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiReceiveWriteResultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReceiveWriteResultConstMeta =>
+      const TaskConstMeta(debugName: "receive_write_result", argNames: []);
+
+  @override
+  Future<void> crateApiRequestCompletion({
+    required String sessionId,
+    required String line,
+    required BigInt cursor,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sessionId, serializer);
+          sse_encode_String(line, serializer);
+          sse_encode_usize(cursor, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiRequestCompletionConstMeta,
+        argValues: [sessionId, line, cursor],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRequestCompletionConstMeta => const TaskConstMeta(
+    debugName: "request_completion",
+    argNames: ["sessionId", "line", "cursor"],
+  );
+
+  @override
+  Future<void> crateApiRequestListDir({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiRequestListDirConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRequestListDirConstMeta =>
+      const TaskConstMeta(debugName: "request_list_dir", argNames: ["path"]);
+
+  @override
+  Future<void> crateApiRequestReadFile({
+    required String path,
+    required BigInt maxSize,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_usize(maxSize, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 49,
+            funcId: 62,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_file_watcher_event_data,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiReceiveFileEventConstMeta,
-        argValues: [],
+        constMeta: kCrateApiRequestReadFileConstMeta,
+        argValues: [path, maxSize],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiReceiveFileEventConstMeta =>
-      const TaskConstMeta(debugName: "receive_file_event", argNames: []);
+  TaskConstMeta get kCrateApiRequestReadFileConstMeta => const TaskConstMeta(
+    debugName: "request_read_file",
+    argNames: ["path", "maxSize"],
+  );
 
   @override
-  Future<SessionHistoryData?> crateApiReceiveSessionHistory() {
+  Future<void> crateApiRequestReadFileChunked({
+    required String path,
+    required int chunkSize,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_u_32(chunkSize, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 50,
+            funcId: 63,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_session_history_data,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiReceiveSessionHistoryConstMeta,
-        argValues: [],
+        constMeta: kCrateApiRequestReadFileChunkedConstMeta,
+        argValues: [path, chunkSize],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiReceiveSessionHistoryConstMeta =>
-      const TaskConstMeta(debugName: "receive_session_history", argNames: []);
+  TaskConstMeta get kCrateApiRequestReadFileChunkedConstMeta =>
+      const TaskConstMeta(
+        debugName: "request_read_file_chunked",
+        argNames: ["path", "chunkSize"],
+      );
 
   @override
-  Future<TerminalEvent> crateApiReceiveTerminalEvent() {
+  Future<void> crateApiRequestShells() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1653,36 +2086,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 51,
+            funcId: 64,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTerminalEvent,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiReceiveTerminalEventConstMeta,
+        constMeta: kCrateApiRequestShellsConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiReceiveTerminalEventConstMeta =>
-      const TaskConstMeta(debugName: "receive_terminal_event", argNames: []);
+  TaskConstMeta get kCrateApiRequestShellsConstMeta =>
+      const TaskConstMeta(debugName: "request_shells", argNames: []);
 
   @override
-  Future<void> crateApiRequestListDir({required String path}) {
+  Future<void> crateApiRequestUnwatchDir({required String watcherId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
+          sse_encode_String(watcherId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 52,
+            funcId: 65,
             port: port_,
           );
         },
@@ -1690,31 +2122,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiRequestListDirConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiRequestUnwatchDirConstMeta,
+        argValues: [watcherId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRequestListDirConstMeta =>
-      const TaskConstMeta(debugName: "request_list_dir", argNames: ["path"]);
+  TaskConstMeta get kCrateApiRequestUnwatchDirConstMeta => const TaskConstMeta(
+    debugName: "request_unwatch_dir",
+    argNames: ["watcherId"],
+  );
 
   @override
-  Future<void> crateApiRequestReadFile({
-    required String path,
-    required BigInt maxSize,
-  }) {
+  Future<void> crateApiRequestWatchDir({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(path, serializer);
-          sse_encode_usize(maxSize, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 53,
+            funcId: 66,
             port: port_,
           );
         },
@@ -1722,29 +2152,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiRequestReadFileConstMeta,
-        argValues: [path, maxSize],
+        constMeta: kCrateApiRequestWatchDirConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRequestReadFileConstMeta => const TaskConstMeta(
-    debugName: "request_read_file",
-    argNames: ["path", "maxSize"],
-  );
+  TaskConstMeta get kCrateApiRequestWatchDirConstMeta =>
+      const TaskConstMeta(debugName: "request_watch_dir", argNames: ["path"]);
 
   @override
-  Future<void> crateApiRequestUnwatchDir({required String watcherId}) {
+  Future<void> crateApiRequestWriteChunk({
+    required String path,
+    required BigInt offset,
+    required List<int> data,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(watcherId, serializer);
+          sse_encode_String(path, serializer);
+          sse_encode_u_64(offset, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 54,
+            funcId: 67,
             port: port_,
           );
         },
@@ -1752,29 +2186,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiRequestUnwatchDirConstMeta,
-        argValues: [watcherId],
+        constMeta: kCrateApiRequestWriteChunkConstMeta,
+        argValues: [path, offset, data],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRequestUnwatchDirConstMeta => const TaskConstMeta(
-    debugName: "request_unwatch_dir",
-    argNames: ["watcherId"],
+  TaskConstMeta get kCrateApiRequestWriteChunkConstMeta => const TaskConstMeta(
+    debugName: "request_write_chunk",
+    argNames: ["path", "offset", "data"],
   );
 
   @override
-  Future<void> crateApiRequestWatchDir({required String path}) {
+  Future<void> crateApiRequestWriteFile({
+    required String path,
+    required List<int> data,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(path, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 55,
+            funcId: 68,
             port: port_,
           );
         },
@@ -1782,15 +2220,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiRequestWatchDirConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiRequestWriteFileConstMeta,
+        argValues: [path, data],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRequestWatchDirConstMeta =>
-      const TaskConstMeta(debugName: "request_watch_dir", argNames: ["path"]);
+  TaskConstMeta get kCrateApiRequestWriteFileConstMeta => const TaskConstMeta(
+    debugName: "request_write_file",
+    argNames: ["path", "data"],
+  );
 
   @override
   Future<void> crateApiResizePty({required int rows, required int cols}) {
@@ -1803,7 +2243,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 56,
+            funcId: 69,
             port: port_,
           );
         },
@@ -1831,7 +2271,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 57,
+            funcId: 70,
             port: port_,
           );
         },
@@ -1859,7 +2299,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 58,
+            funcId: 71,
             port: port_,
           );
         },
@@ -1894,7 +2334,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 59,
+            funcId: 72,
             port: port_,
           );
         },
@@ -1926,7 +2366,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 60,
+            funcId: 73,
             port: port_,
           );
         },
@@ -1960,7 +2400,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 61,
+              funcId: 74,
               port: port_,
             );
           },
@@ -1992,7 +2432,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 62,
+            funcId: 75,
             port: port_,
           );
         },
@@ -2019,7 +2459,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 63,
+            funcId: 76,
             port: port_,
           );
         },
@@ -2204,6 +2644,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as bool;
   }
 
+  @protected
+  FileChunkData dco_decode_box_autoadd_file_chunk_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_file_chunk_data(raw);
+  }
+
   @protected
   FileContentData dco_decode_box_autoadd_file_content_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2251,6 +2697,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_vibe_input(raw);
   }
 
+  @protected
+  WriteResultData dco_decode_box_autoadd_write_result_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_write_result_data(raw);
+  }
+
+  @protected
+  FileChunkData dco_decode_file_chunk_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return FileChunkData(
+      path: dco_decode_String(arr[0]),
+      chunkIndex: dco_decode_u_32(arr[1]),
+      data: dco_decode_list_prim_u_8_strict(arr[2]),
+      hasMore: dco_decode_bool(arr[3]),
+      receivedBytes: dco_decode_u_64(arr[4]),
+      totalSize: dco_decode_u_64(arr[5]),
+    );
+  }
+
   @protected
   FileContentData dco_decode_file_content_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2331,6 +2799,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_String(raw);
   }
 
+  @protected
+  FileChunkData? dco_decode_opt_box_autoadd_file_chunk_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_file_chunk_data(raw);
+  }
+
   @protected
   FileContentData? dco_decode_opt_box_autoadd_file_content_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2376,6 +2850,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
   }
 
+  @protected
+  WriteResultData? dco_decode_opt_box_autoadd_write_result_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_write_result_data(raw);
+  }
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_String(raw);
+  }
+
   @protected
   (int, List<DirEntry>, bool)
   dco_decode_record_u_32_list_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_dir_entry_bool(
@@ -2505,6 +2991,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  WriteResultData dco_decode_write_result_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return WriteResultData(
+      path: dco_decode_String(arr[0]),
+      bytesWritten: dco_decode_u_64(arr[1]),
+      errorKind: dco_decode_String(arr[2]),
+      error: dco_decode_String(arr[3]),
+    );
+  }
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2678,6 +3178,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getUint8() != 0;
   }
 
+  @protected
+  FileChunkData sse_decode_box_autoadd_file_chunk_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_file_chunk_data(deserializer));
+  }
+
   @protected
   FileContentData sse_decode_box_autoadd_file_content_data(
     SseDeserializer deserializer,
@@ -2733,6 +3241,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_vibe_input(deserializer));
   }
 
+  @protected
+  WriteResultData sse_decode_box_autoadd_write_result_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_write_result_data(deserializer));
+  }
+
+  @protected
+  FileChunkData sse_decode_file_chunk_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_chunkIndex = sse_decode_u_32(deserializer);
+    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_hasMore = sse_decode_bool(deserializer);
+    var var_receivedBytes = sse_decode_u_64(deserializer);
+    var var_totalSize = sse_decode_u_64(deserializer);
+    return FileChunkData(
+      path: var_path,
+      chunkIndex: var_chunkIndex,
+      data: var_data,
+      hasMore: var_hasMore,
+      receivedBytes: var_receivedBytes,
+      totalSize: var_totalSize,
+    );
+  }
+
   @protected
   FileContentData sse_decode_file_content_data(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2845,6 +3380,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  FileChunkData? sse_decode_opt_box_autoadd_file_chunk_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_file_chunk_data(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   FileContentData? sse_decode_opt_box_autoadd_file_content_data(
     SseDeserializer deserializer,
@@ -2911,6 +3459,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  WriteResultData? sse_decode_opt_box_autoadd_write_result_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_write_result_data(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   (int, List<DirEntry>, bool)
   sse_decode_record_u_32_list_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_dir_entry_bool(
@@ -3041,6 +3613,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  WriteResultData sse_decode_write_result_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_bytesWritten = sse_decode_u_64(deserializer);
+    var var_errorKind = sse_decode_String(deserializer);
+    var var_error = sse_decode_String(deserializer);
+    return WriteResultData(
+      path: var_path,
+      bytesWritten: var_bytesWritten,
+      errorKind: var_errorKind,
+      error: var_error,
+    );
+  }
+
   @protected
   void sse_encode_AnyhowException(
     AnyhowException self,
@@ -3237,6 +3824,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8(self ? 1 : 0);
   }
 
+  @protected
+  void sse_encode_box_autoadd_file_chunk_data(
+    FileChunkData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_file_chunk_data(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_file_content_data(
     FileContentData self,
@@ -3301,6 +3897,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_vibe_input(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_write_result_data(
+    WriteResultData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_write_result_data(self, serializer);
+  }
+
+  @protected
+  void sse_encode_file_chunk_data(
+    FileChunkData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_u_32(self.chunkIndex, serializer);
+    sse_encode_list_prim_u_8_strict(self.data, serializer);
+    sse_encode_bool(self.hasMore, serializer);
+    sse_encode_u_64(self.receivedBytes, serializer);
+    sse_encode_u_64(self.totalSize, serializer);
+  }
+
   @protected
   void sse_encode_file_content_data(
     FileContentData self,
@@ -3403,6 +4022,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_file_chunk_data(
+    FileChunkData? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_file_chunk_data(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_file_content_data(
     FileContentData? self,
@@ -3469,6 +4101,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_write_result_data(
+    WriteResultData? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_write_result_data(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_list_String(
+    List<String>? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_String(self, serializer);
+    }
+  }
+
   @protected
   void
   sse_encode_record_u_32_list_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_dir_entry_bool(
@@ -3589,6 +4247,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_strict(data, serializer);
     }
   }
+
+  @protected
+  void sse_encode_write_result_data(
+    WriteResultData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_u_64(self.bytesWritten, serializer);
+    sse_encode_String(self.errorKind, serializer);
+    sse_encode_String(self.error, serializer);
+  }
 }
 
 @sealed
@@ -123,6 +123,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  FileChunkData dco_decode_box_autoadd_file_chunk_data(dynamic raw);
+
   @protected
   FileContentData dco_decode_box_autoadd_file_content_data(dynamic raw);
 
@@ -149,6 +152,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   VibeInput dco_decode_box_autoadd_vibe_input(dynamic raw);
 
+  @protected
+  WriteResultData dco_decode_box_autoadd_write_result_data(dynamic raw);
+
+  @protected
+  FileChunkData dco_decode_file_chunk_data(dynamic raw);
+
   @protected
   FileContentData dco_decode_file_content_data(dynamic raw);
 
@@ -179,6 +188,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
+  @protected
+  FileChunkData? dco_decode_opt_box_autoadd_file_chunk_data(dynamic raw);
+
   @protected
   FileContentData? dco_decode_opt_box_autoadd_file_content_data(dynamic raw);
 
@@ -201,6 +213,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  WriteResultData? dco_decode_opt_box_autoadd_write_result_data(dynamic raw);
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw);
+
   @protected
   (int, List<DirEntry>, bool)
   dco_decode_record_u_32_list_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_dir_entry_bool(
@@ -240,6 +258,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   VibeInput dco_decode_vibe_input(dynamic raw);
 
+  @protected
+  WriteResultData dco_decode_write_result_data(dynamic raw);
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
@@ -327,6 +348,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  FileChunkData sse_decode_box_autoadd_file_chunk_data(
+    SseDeserializer deserializer,
+  );
+
   @protected
   FileContentData sse_decode_box_autoadd_file_content_data(
     SseDeserializer deserializer,
@@ -359,6 +385,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   VibeInput sse_decode_box_autoadd_vibe_input(SseDeserializer deserializer);
 
+  @protected
+  WriteResultData sse_decode_box_autoadd_write_result_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FileChunkData sse_decode_file_chunk_data(SseDeserializer deserializer);
+
   @protected
   FileContentData sse_decode_file_content_data(SseDeserializer deserializer);
 
@@ -391,6 +425,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
+  @protected
+  FileChunkData? sse_decode_opt_box_autoadd_file_chunk_data(
+    SseDeserializer deserializer,
+  );
+
   @protected
   FileContentData? sse_decode_opt_box_autoadd_file_content_data(
     SseDeserializer deserializer,
@@ -415,6 +454,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  WriteResultData? sse_decode_opt_box_autoadd_write_result_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
   @protected
   (int, List<DirEntry>, bool)
   sse_decode_record_u_32_list_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_dir_entry_bool(
@@ -456,6 +503,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   VibeInput sse_decode_vibe_input(SseDeserializer deserializer);
 
+  @protected
+  WriteResultData sse_decode_write_result_data(SseDeserializer deserializer);
+
   @protected
   void sse_encode_AnyhowException(
     AnyhowException self,
@@ -559,6 +609,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_file_chunk_data(
+    FileChunkData self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_file_content_data(
     FileContentData self,
@@ -599,6 +655,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_write_result_data(
+    WriteResultData self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_file_chunk_data(FileChunkData self, SseSerializer serializer);
+
   @protected
   void sse_encode_file_content_data(
     FileContentData self,
@@ -642,6 +707,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_file_chunk_data(
+    FileChunkData? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_file_content_data(
     FileContentData? self,
@@ -670,6 +741,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_write_result_data(
+    WriteResultData? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+
   @protected
   void
   sse_encode_record_u_32_list_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_dir_entry_bool(
@@ -718,6 +798,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void sse_encode_vibe_input(VibeInput self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_write_result_data(
+    WriteResultData self,
+    SseSerializer serializer,
+  );
 }
 
 // Section: wire_class